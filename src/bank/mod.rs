@@ -1,4 +1,7 @@
-use crate::{error::*, spec::BRASIL_API_URL};
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::*,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -11,33 +14,51 @@ pub struct Bank {
     pub fullname: Option<String>,
 }
 
-pub struct BankService {
-    base_url: String,
+pub struct BankService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl BankService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_all_banks(&self) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/banks/v1", self.base_url);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> BankService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
-    async fn get_bank_by_code(&self, code: i32) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/banks/v1/{}", self.base_url, code);
+    async fn get_all_banks(&self) -> Result<Response, Error> {
+        self.client.get("bank.list", "/api/banks/v1").await
+    }
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+    async fn get_bank_by_code(&self, code: i32) -> Result<Response, Error> {
+        self.client
+            .get("bank.get", &format!("/api/banks/v1/{code}"))
+            .await
+    }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Retorna informações de todos os bancos utilizando este cliente. Veja [`get_all_banks`].
+    pub async fn get_all_banks(&self) -> Result<Vec<Bank>, Error> {
+        let response = BankService::with_client(self).get_all_banks().await?;
+
+        response.json()
+    }
+
+    /// Busca um banco pelo código utilizando este cliente. Veja [`get_bank`].
+    pub async fn get_bank(&self, code: i32) -> Result<Bank, Error> {
+        let response = BankService::with_client(self)
+            .get_bank_by_code(code)
+            .await?;
+
+        response.json()
     }
 }
 
@@ -58,14 +79,7 @@ impl BankService {
 ///    let banks:Vec<Bank> = bank::get_all_banks().await.unwrap();
 /// }
 pub async fn get_all_banks() -> Result<Vec<Bank>, Error> {
-    let bank_service = BankService::new(BRASIL_API_URL);
-
-    let response = bank_service.get_all_banks().await?;
-
-    let body = response.text().await.unwrap();
-    let banks: Vec<Bank> = serde_json::from_str(&body).unwrap();
-
-    Ok(banks)
+    default_client().get_all_banks().await
 }
 
 /// #### `get_bank(code: i32)`
@@ -87,14 +101,7 @@ pub async fn get_all_banks() -> Result<Vec<Bank>, Error> {
 /// }
 /// ```
 pub async fn get_bank(code: i32) -> Result<Bank, Error> {
-    let bank_service = BankService::new(BRASIL_API_URL);
-
-    let response = bank_service.get_bank_by_code(code).await?;
-
-    let body = response.text().await.unwrap();
-    let bank: Bank = serde_json::from_str(&body).unwrap();
-
-    Ok(bank)
+    default_client().get_bank(code).await
 }
 
 #[cfg(test)]
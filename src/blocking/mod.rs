@@ -0,0 +1,354 @@
+//! API síncrona da biblioteca, disponível com a feature `blocking`.
+//!
+//! Cada módulo da biblioteca tem um espelho aqui, com as mesmas funções, tipos e erros,
+//! mas sem `async`. As requisições são executadas em um runtime próprio de uma única
+//! thread, criado na primeira chamada, e usam um cliente padrão próprio.
+//!
+//! As funções deste módulo não podem ser chamadas de dentro de um runtime async, pois
+//! bloqueiam a thread atual até a resposta chegar.
+//!
+//! # Exemplo
+//! ```rust
+//! use brasilapi::blocking::cep;
+//!
+//! fn main() {
+//!     let cep = cep::get_cep("01001000").unwrap();
+//!
+//!     println!("Estado: {}", cep.state);
+//! }
+//! ```
+// Todas as funções deste módulo retornam `Result<_, Error>`; veja a nota em [`Error`](crate::error::Error).
+#![allow(clippy::result_large_err)]
+
+use std::{future::Future, sync::OnceLock};
+
+use crate::client::BrasilApiClient;
+
+static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+static CLIENT: OnceLock<BrasilApiClient> = OnceLock::new();
+
+/// Executa `future` até o fim no runtime do modo síncrono.
+///
+/// Útil para usar um [`BrasilApiClient`] configurado no código síncrono:
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use brasilapi::{blocking, client::BrasilApiClient};
+///
+/// let client = BrasilApiClient::builder()
+///     .timeout(Duration::from_secs(10))
+///     .build()
+///     .unwrap();
+///
+/// let cep = blocking::block_on(client.get_cep("01001000")).unwrap();
+/// ```
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME
+        .get_or_init(|| {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to build the blocking runtime")
+        })
+        .block_on(future)
+}
+
+/// Cliente utilizado pelas funções síncronas.
+///
+/// É separado do cliente das funções async porque as conexões do pool ficam presas ao
+/// runtime em que foram abertas.
+fn client() -> &'static BrasilApiClient {
+    CLIENT.get_or_init(BrasilApiClient::new)
+}
+
+/// Versão síncrona de [`crate::bank`].
+#[cfg(feature = "bank")]
+pub mod bank {
+    pub use crate::bank::Bank;
+    use crate::error::Error;
+
+    /// Veja [`crate::bank::get_all_banks`].
+    pub fn get_all_banks() -> Result<Vec<Bank>, Error> {
+        super::block_on(super::client().get_all_banks())
+    }
+
+    /// Veja [`crate::bank::get_bank`].
+    pub fn get_bank(code: i32) -> Result<Bank, Error> {
+        super::block_on(super::client().get_bank(code))
+    }
+}
+
+/// Versão síncrona de [`crate::cep`].
+#[cfg(feature = "cep")]
+pub mod cep {
+    use std::collections::HashMap;
+
+    pub use crate::cep::{
+        resolve_state, Cep, CepCode, CepError, CepProvider, CepState, CepVersion, Coordinates,
+        IntoCepCode, Location, Region,
+    };
+    use crate::error::Error;
+
+    /// Veja [`crate::cep::get_cep`].
+    pub fn get_cep(cep_code: impl IntoCepCode) -> Result<Cep, Error> {
+        super::block_on(super::client().get_cep(cep_code))
+    }
+
+    /// Veja [`crate::cep::get_cep_with_version`].
+    pub fn get_cep_with_version(
+        cep_code: impl IntoCepCode,
+        version: CepVersion,
+    ) -> Result<Cep, Error> {
+        super::block_on(super::client().get_cep_with_version(cep_code, version))
+    }
+
+    /// Veja [`crate::cep::validate`].
+    pub fn validate(cep_code: impl IntoCepCode) -> Result<bool, Error> {
+        super::block_on(super::client().validate_cep(cep_code))
+    }
+
+    /// Veja [`crate::cep::get_many`].
+    pub fn get_many<I>(cep_codes: I, concurrency: usize) -> HashMap<String, Result<Cep, Error>>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        super::block_on(super::client().get_ceps(cep_codes, concurrency))
+    }
+}
+
+/// Versão síncrona de [`crate::cnpj`].
+#[cfg(feature = "cnpj")]
+pub mod cnpj {
+    use std::collections::HashMap;
+
+    pub use crate::cnpj::{Cnaes, Cnpj, Qsa};
+    use crate::error::Error;
+
+    /// Veja [`crate::cnpj::get_cnpj`].
+    pub fn get_cnpj(cnpj: &str) -> Result<Cnpj, Error> {
+        super::block_on(super::client().get_cnpj(cnpj))
+    }
+
+    /// Veja [`crate::cnpj::get_many`].
+    pub fn get_many<I>(cnpjs: I, concurrency: usize) -> HashMap<String, Result<Cnpj, Error>>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        super::block_on(super::client().get_cnpjs(cnpjs, concurrency))
+    }
+}
+
+/// Versão síncrona de [`crate::corretoras`].
+#[cfg(feature = "corretoras")]
+pub mod corretoras {
+    pub use crate::corretoras::Corretora;
+    use crate::error::Error;
+
+    /// Veja [`crate::corretoras::get_corretoras`].
+    pub fn get_corretoras() -> Result<Vec<Corretora>, Error> {
+        super::block_on(super::client().get_corretoras())
+    }
+
+    /// Veja [`crate::corretoras::get_corretora`].
+    pub fn get_corretora(cnpj: &str) -> Result<Corretora, Error> {
+        super::block_on(super::client().get_corretora(cnpj))
+    }
+}
+
+/// Versão síncrona de [`crate::ddd`].
+#[cfg(feature = "ddd")]
+pub mod ddd {
+    use std::collections::HashMap;
+
+    pub use crate::ddd::{Ddd, Regiao};
+    use crate::error::Error;
+
+    /// Veja [`crate::ddd::get_ddd`].
+    pub fn get_ddd(ddd: &str) -> Result<Ddd, Error> {
+        super::block_on(super::client().get_ddd(ddd))
+    }
+
+    /// Veja [`crate::ddd::ddd_exists`].
+    pub fn ddd_exists(ddd: &str) -> Result<bool, Error> {
+        super::block_on(super::client().ddd_exists(ddd))
+    }
+
+    /// Veja [`crate::ddd::get_many`].
+    pub fn get_many<I>(ddds: I, concurrency: usize) -> HashMap<String, Result<Ddd, Error>>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        super::block_on(super::client().get_ddds(ddds, concurrency))
+    }
+}
+
+/// Versão síncrona de [`crate::fipe`].
+#[cfg(feature = "fipe")]
+pub mod fipe {
+    use crate::error::Error;
+    pub use crate::fipe::{Brand, ReferenceTable, Vehicle, VehicleType};
+
+    /// Veja [`crate::fipe::get_brands`].
+    pub fn get_brands(
+        vehicle_type: VehicleType,
+        reference_table: Option<i64>,
+    ) -> Result<Vec<Brand>, Error> {
+        super::block_on(super::client().get_fipe_brands(vehicle_type, reference_table))
+    }
+
+    /// Veja [`crate::fipe::get_vehicles`].
+    pub fn get_vehicles(
+        fipe_code: &str,
+        reference_table: Option<i64>,
+    ) -> Result<Vec<Vehicle>, Error> {
+        super::block_on(super::client().get_fipe_vehicles(fipe_code, reference_table))
+    }
+
+    /// Veja [`crate::fipe::get_reference_tables`].
+    pub fn get_reference_tables() -> Result<Vec<ReferenceTable>, Error> {
+        super::block_on(super::client().get_fipe_reference_tables())
+    }
+}
+
+/// Versão síncrona de [`crate::holidays`].
+#[cfg(feature = "holidays")]
+pub mod holidays {
+    use crate::error::Error;
+    pub use crate::holidays::Holiday;
+
+    /// Veja [`crate::holidays::get_holidays`].
+    pub fn get_holidays(year: &str) -> Result<Vec<Holiday>, Error> {
+        super::block_on(super::client().get_holidays(year))
+    }
+
+    /// Veja [`crate::holidays::get_holiday`].
+    pub fn get_holiday(year: &str, month: &str, day: &str) -> Result<Holiday, Error> {
+        super::block_on(super::client().get_holiday(year, month, day))
+    }
+}
+
+/// Versão síncrona de [`crate::ibge`].
+#[cfg(feature = "ibge")]
+pub mod ibge {
+    use crate::error::Error;
+    pub use crate::ibge::{MunicipalitiesProvider, Municipality, State, StateRegion};
+
+    /// Veja [`crate::ibge::get_municipalities`].
+    pub fn get_municipalities(
+        uf: &str,
+        providers: Option<Vec<MunicipalitiesProvider>>,
+    ) -> Result<Vec<Municipality>, Error> {
+        super::block_on(super::client().get_municipalities(uf, providers))
+    }
+
+    /// Veja [`crate::ibge::get_all_states`].
+    pub fn get_all_states() -> Result<Vec<State>, Error> {
+        super::block_on(super::client().get_all_states())
+    }
+
+    /// Veja [`crate::ibge::get_state`].
+    pub fn get_state(code: &str) -> Result<State, Error> {
+        super::block_on(super::client().get_state(code))
+    }
+}
+
+/// Versão síncrona de [`crate::isbn`].
+#[cfg(feature = "isbn")]
+pub mod isbn {
+    use std::collections::HashMap;
+
+    use crate::error::Error;
+    pub use crate::isbn::{Dimensions, Format, Isbn, Provider, RetailPrice, Unit};
+
+    /// Veja [`crate::isbn::get_isbn`].
+    pub fn get_isbn(isbn_code: &str) -> Result<Isbn, Error> {
+        super::block_on(super::client().get_isbn(isbn_code))
+    }
+
+    /// Veja [`crate::isbn::validate`].
+    pub fn validate(isbn_code: &str) -> Result<bool, Error> {
+        super::block_on(super::client().validate_isbn(isbn_code))
+    }
+
+    /// Veja [`crate::isbn::get_many`].
+    pub fn get_many<I>(isbn_codes: I, concurrency: usize) -> HashMap<String, Result<Isbn, Error>>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        super::block_on(super::client().get_isbns(isbn_codes, concurrency))
+    }
+}
+
+/// Versão síncrona de [`crate::pix`].
+#[cfg(feature = "pix")]
+pub mod pix {
+    use crate::error::Error;
+    pub use crate::pix::Participant;
+
+    /// Veja [`crate::pix::get_participants`].
+    pub fn get_participants() -> Result<Vec<Participant>, Error> {
+        super::block_on(super::client().get_pix_participants())
+    }
+}
+
+/// Versão síncrona de [`crate::registrobr`].
+#[cfg(feature = "registrobr")]
+pub mod registrobr {
+    use crate::error::Error;
+    pub use crate::registrobr::Domain;
+
+    /// Veja [`crate::registrobr::get_domain_by_name`].
+    pub fn get_domain_by_name(name: &str) -> Result<Domain, Error> {
+        super::block_on(super::client().get_domain_by_name(name))
+    }
+}
+
+/// Versão síncrona de [`crate::taxas`].
+#[cfg(feature = "taxas")]
+pub mod taxas {
+    use crate::error::Error;
+    pub use crate::taxas::Taxa;
+
+    /// Veja [`crate::taxas::get_taxa`].
+    pub fn get_taxa(sigla: &str) -> Result<Taxa, Error> {
+        super::block_on(super::client().get_taxa(sigla))
+    }
+
+    /// Veja [`crate::taxas::list_taxas`].
+    pub fn list_taxas() -> Result<Vec<Taxa>, Error> {
+        super::block_on(super::client().list_taxas())
+    }
+}
+
+#[cfg(test)]
+mod blocking_tests {
+    use super::*;
+
+    #[cfg(feature = "taxas")]
+    #[test]
+    fn block_on_runs_client_futures_without_async_context() {
+        use crate::client::InMemoryTransport;
+
+        let transport = InMemoryTransport::new().route(
+            "/api/taxas/v1/SELIC",
+            200,
+            r#"{"nome": "Selic", "valor": 13.75}"#,
+        );
+        let client = BrasilApiClient::with_transport(transport);
+
+        let taxa = block_on(client.get_taxa("SELIC")).unwrap();
+        let missing = block_on(client.get_taxa("XYZ")).unwrap_err();
+
+        assert_eq!(taxa.nome, "Selic");
+        assert_eq!(missing.code, Some(404));
+    }
+
+    #[test]
+    fn blocking_client_is_separate_from_async_default_client() {
+        assert!(std::ptr::eq(client(), client()));
+        assert!(!std::ptr::eq(client(), crate::client::default_client()));
+    }
+}
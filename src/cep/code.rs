@@ -0,0 +1,174 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Errored};
+
+/// CEP normalizado: exatamente 8 dígitos.
+///
+/// A conversão a partir de texto remove espaços, hífen e ponto, então `"01001-000"`,
+/// `" 01001000 "` e `"01.001-000"` resultam no mesmo CEP. Qualquer outro caractere, ou
+/// um número de dígitos diferente de 8, é rejeitado com [`Errored::InvalidInput`].
+///
+/// # Exemplo
+/// ```rust
+/// use brasilapi::cep::CepCode;
+///
+/// let cep: CepCode = "01001-000".parse().unwrap();
+///
+/// assert_eq!(cep.as_str(), "01001000");
+/// assert_eq!(cep.to_string(), "01001-000");
+/// assert!("abc/../x".parse::<CepCode>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CepCode(String);
+
+impl CepCode {
+    /// Os 8 dígitos do CEP, sem máscara (`01001000`).
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Os 5 primeiros dígitos do CEP (`01001`).
+    pub fn prefix(&self) -> &str {
+        &self.0[..5]
+    }
+}
+
+impl FromStr for CepCode {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let digits: String = value
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '.'))
+            .collect();
+
+        if digits.len() != 8 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Error::new(
+                format!("{value:?} is not a valid CEP: expected 8 digits"),
+                Errored::InvalidInput,
+                None,
+            ));
+        }
+
+        Ok(Self(digits))
+    }
+}
+
+impl TryFrom<String> for CepCode {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<CepCode> for String {
+    fn from(cep: CepCode) -> Self {
+        cep.0
+    }
+}
+
+/// Formata o CEP com a máscara `01001-000`.
+impl fmt::Display for CepCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", &self.0[..5], &self.0[5..])
+    }
+}
+
+/// Tipos aceitos como CEP pelas funções do módulo: texto (`&str`, `String`) ou um
+/// [`CepCode`] já validado.
+pub trait IntoCepCode {
+    #[allow(clippy::result_large_err)]
+    fn into_cep_code(self) -> Result<CepCode, Error>;
+}
+
+impl IntoCepCode for CepCode {
+    fn into_cep_code(self) -> Result<CepCode, Error> {
+        Ok(self)
+    }
+}
+
+impl IntoCepCode for &CepCode {
+    fn into_cep_code(self) -> Result<CepCode, Error> {
+        Ok(self.clone())
+    }
+}
+
+impl IntoCepCode for &str {
+    fn into_cep_code(self) -> Result<CepCode, Error> {
+        self.parse()
+    }
+}
+
+impl IntoCepCode for String {
+    fn into_cep_code(self) -> Result<CepCode, Error> {
+        self.parse()
+    }
+}
+
+impl IntoCepCode for &String {
+    fn into_cep_code(self) -> Result<CepCode, Error> {
+        self.parse()
+    }
+}
+
+#[cfg(test)]
+mod code_tests {
+    use super::*;
+
+    #[test]
+    fn masks_and_whitespace_are_normalized() {
+        for input in ["01001000", "01001-000", " 01001000 ", "01.001-000"] {
+            assert_eq!(input.parse::<CepCode>().unwrap().as_str(), "01001000");
+        }
+
+        let cep: CepCode = "01001000".parse().unwrap();
+        assert_eq!(cep.to_string(), "01001-000");
+        assert_eq!(cep.prefix(), "01001");
+    }
+
+    #[test]
+    fn malformed_input_is_rejected() {
+        for input in [
+            "",
+            "0100100",
+            "010010000",
+            "abc/../x",
+            "0100100a",
+            "01001/000",
+        ] {
+            let error = input.parse::<CepCode>().unwrap_err();
+            assert_eq!(error.error, Errored::InvalidInput, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn serializes_as_digits() {
+        let cep: CepCode = serde_json::from_str(r#""01001-000""#).unwrap();
+
+        assert_eq!(serde_json::to_string(&cep).unwrap(), r#""01001000""#);
+        assert!(serde_json::from_str::<CepCode>(r#""abc""#).is_err());
+    }
+
+    #[tokio::test]
+    async fn invalid_cep_fails_before_any_request() {
+        use crate::client::{BrasilApiClient, InMemoryTransport};
+
+        let transport = InMemoryTransport::new().route(
+            "/api/cep/v2/01001000",
+            200,
+            r#"{"cep": "01001000", "state": "SP", "city": "São Paulo", "neighborhood": "Sé", "street": "Praça da Sé", "service": "open-cep"}"#,
+        );
+        let client = BrasilApiClient::with_transport(transport.clone());
+
+        let error = client.get_cep("abc/../x").await.unwrap_err();
+
+        assert_eq!(error.error, Errored::InvalidInput);
+        assert!(!client.validate_cep("0100").await.unwrap());
+        assert_eq!(client.get_cep("01001-000").await.unwrap().state, "SP");
+        assert_eq!(transport.requests().len(), 1);
+    }
+}
@@ -1,27 +1,106 @@
-use crate::{error::*, spec::BRASIL_API_URL};
+use crate::{
+    client::{batch, default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::*,
+};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+mod code;
+mod provider;
+mod uf;
+
+pub use code::{CepCode, IntoCepCode};
+pub use provider::{CepProvider, CepVersion};
+pub use uf::{resolve_state, CepState, Region};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Cep {
     pub cep: String,
     pub state: String,
     pub city: String,
     pub neighborhood: String,
     pub street: String,
-    pub service: String,
+    pub service: CepProvider,
+    /// Geolocalização do CEP, quando o provider a retorna. Sempre `None` na
+    /// [`CepVersion::V1`].
+    #[serde(default)]
+    pub location: Option<Location>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Location {
     #[serde(rename = "type")]
     pub kind: String,
+    #[serde(default)]
     pub coordinates: Coordinates,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Coordenadas de um CEP, em graus.
+///
+/// A API retorna as coordenadas como texto e às vezes sem valor (`""`); nesse caso o
+/// campo fica `None`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub struct Coordinates {
-    pub longitude: String,
-    pub latitude: String,
+    #[serde(default, deserialize_with = "coordinate")]
+    pub longitude: Option<f64>,
+    #[serde(default, deserialize_with = "coordinate")]
+    pub latitude: Option<f64>,
+}
+
+/// Raio médio da Terra, em quilômetros.
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+impl Coordinates {
+    /// Distância em quilômetros até `other`, pela fórmula de haversine.
+    ///
+    /// Retorna `None` se alguma das coordenadas estiver ausente.
+    pub fn distance_km(&self, other: &Coordinates) -> Option<f64> {
+        let (lat1, lon1) = (self.latitude?.to_radians(), self.longitude?.to_radians());
+        let (lat2, lon2) = (other.latitude?.to_radians(), other.longitude?.to_radians());
+
+        let a = ((lat2 - lat1) / 2.0).sin().powi(2)
+            + lat1.cos() * lat2.cos() * ((lon2 - lon1) / 2.0).sin().powi(2);
+
+        Some(2.0 * EARTH_RADIUS_KM * a.sqrt().asin())
+    }
+}
+
+impl Cep {
+    /// Coordenadas do CEP, se a resposta trouxer latitude e longitude.
+    pub fn coordinates(&self) -> Option<Coordinates> {
+        self.location
+            .as_ref()
+            .map(|location| location.coordinates)
+            .filter(|c| c.latitude.is_some() && c.longitude.is_some())
+    }
+
+    /// Distância em quilômetros até `other`, em linha reta. Veja [`Coordinates::distance_km`].
+    pub fn distance_km(&self, other: &Cep) -> Option<f64> {
+        self.coordinates()?.distance_km(&other.coordinates()?)
+    }
+}
+
+/// Aceita a coordenada como texto (`"-46.6339"`), número, texto vazio ou `null`.
+fn coordinate<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Number(f64),
+        Text(String),
+    }
+
+    match Option::<Raw>::deserialize(deserializer)? {
+        Some(Raw::Number(value)) => Ok(Some(value)),
+        Some(Raw::Text(text)) if text.trim().is_empty() => Ok(None),
+        Some(Raw::Text(text)) => text
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        None => Ok(None),
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -34,28 +113,41 @@ pub struct CepError {
     pub errors: Vec<Error>,
 }
 
-pub struct CepService {
-    base_url: String,
+pub struct CepService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl CepService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_cep_request(&self, cep_code: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/cep/v2/{}", self.base_url, cep_code);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> CepService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
-    async fn validate_cep(&self, cep_code: &str) -> Result<bool, Error> {
-        let response = self.get_cep_request(cep_code).await;
+    async fn get_cep_request(
+        &self,
+        cep_code: &CepCode,
+        version: CepVersion,
+    ) -> Result<Response, Error> {
+        self.client
+            .get(version.operation(), &version.path(cep_code))
+            .await
+    }
+
+    async fn validate_cep(&self, cep_code: impl IntoCepCode) -> Result<bool, Error> {
+        let Ok(cep_code) = cep_code.into_cep_code() else {
+            return Ok(false);
+        };
+
+        let response = self.get_cep_request(&cep_code, CepVersion::V2).await;
 
         match response {
             Ok(_) => Ok(true),
@@ -67,13 +159,41 @@ impl CepService {
     }
 }
 
-/// #### `get_cep(cep_code: &str)`
+impl<T: Transport> BrasilApiClient<T> {
+    /// Busca por CEP utilizando este cliente. Veja [`get_cep`].
+    pub async fn get_cep(&self, cep_code: impl IntoCepCode) -> Result<Cep, Error> {
+        self.get_cep_with_version(cep_code, CepVersion::V2).await
+    }
+
+    /// Busca por CEP na versão `version` da API utilizando este cliente. Veja
+    /// [`get_cep_with_version`].
+    pub async fn get_cep_with_version(
+        &self,
+        cep_code: impl IntoCepCode,
+        version: CepVersion,
+    ) -> Result<Cep, Error> {
+        let cep_code = cep_code.into_cep_code()?;
+        let response = CepService::with_client(self)
+            .get_cep_request(&cep_code, version)
+            .await?;
+
+        response.json()
+    }
+
+    /// Valida um CEP utilizando este cliente. Veja [`validate`].
+    pub async fn validate_cep(&self, cep_code: impl IntoCepCode) -> Result<bool, Error> {
+        CepService::with_client(self).validate_cep(cep_code).await
+    }
+}
+
+/// #### `get_cep(cep_code: impl IntoCepCode)`
 /// Busca por CEP com múltiplos providers de fallback.
 ///
 /// A busca utiliza como fonte principal o OpenCep, caso não encontre o CEP é buscado em diversos outros providers de CEP.
 ///
 /// ### Argumento
-/// * `cep_code:&str` => CEP para ser consultado.
+/// * `cep_code:impl IntoCepCode` => CEP para ser consultado, com ou sem máscara (`01001-000`). Um CEP
+///   sem 8 dígitos retorna [`Errored::InvalidInput`] sem fazer a requisição.
 ///
 /// ### Retorno
 /// * `Result<Cep, Error>`
@@ -87,22 +207,48 @@ impl CepService {
 ///    let cep = cep::get_cep("01001000").await.unwrap();
 /// }
 /// ```
-pub async fn get_cep(cep_code: &str) -> Result<Cep, Error> {
-    let cep_service = CepService::new(BRASIL_API_URL);
-
-    let response = cep_service.get_cep_request(cep_code).await?;
-
-    let body = response.text().await.unwrap();
-    let cep: Cep = serde_json::from_str(&body).unwrap();
+pub async fn get_cep(cep_code: impl IntoCepCode) -> Result<Cep, Error> {
+    default_client().get_cep(cep_code).await
+}
 
-    Ok(cep)
+/// #### `get_cep_with_version(cep_code: impl IntoCepCode, version: CepVersion)`
+/// Busca por CEP escolhendo a versão da API.
+///
+/// A [`CepVersion::V1`] responde mais rápido, mas não traz a geolocalização; use-a
+/// quando só o endereço importa, como em formulários. [`get_cep`] usa a
+/// [`CepVersion::V2`].
+///
+/// ### Argumentos
+/// * `cep_code:impl IntoCepCode` => CEP para ser consultado, com ou sem máscara.
+/// * `version:CepVersion` => Versão do endpoint de CEP.
+///
+/// ### Retorno
+/// * `Result<Cep, Error>`
+///
+/// # Exemplo
+/// ```rust,no_run
+/// use brasilapi::cep::{self, CepVersion};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let cep = cep::get_cep_with_version("01001000", CepVersion::V1).await.unwrap();
+/// }
+/// ```
+pub async fn get_cep_with_version(
+    cep_code: impl IntoCepCode,
+    version: CepVersion,
+) -> Result<Cep, Error> {
+    default_client()
+        .get_cep_with_version(cep_code, version)
+        .await
 }
 
-/// #### `validate(cep_code: &str)`
+/// #### `validate(cep_code: impl IntoCepCode)`
 /// Valida um CEP.
 ///
 /// ### Argumento
-/// * `cep_code:&str` => CEP para ser validado.
+/// * `cep_code:impl IntoCepCode` => CEP para ser validado. Um CEP sem 8 dígitos retorna `Ok(false)`
+///   sem fazer a requisição.
 ///
 /// Retorno
 /// * `Result<bool, Error>`
@@ -115,9 +261,25 @@ pub async fn get_cep(cep_code: &str) -> Result<Cep, Error> {
 /// async fn main() {
 ///   let is_valid = cep::validate("01001000").await.unwrap();  
 /// }
-pub async fn validate(cep_code: &str) -> Result<bool, Error> {
-    let cep_service = CepService::new(BRASIL_API_URL);
-    cep_service.validate_cep(cep_code).await
+pub async fn validate(cep_code: impl IntoCepCode) -> Result<bool, Error> {
+    default_client().validate_cep(cep_code).await
+}
+
+/// Chave de um CEP nas consultas em lote: `01001-000` e `01001000` são o mesmo CEP.
+fn batch_key(cep_code: &str) -> String {
+    cep_code
+        .parse::<CepCode>()
+        .map(String::from)
+        .unwrap_or_else(|_| cep_code.to_string())
+}
+
+batch::batch_lookups! {
+    module: cep,
+    item: Cep,
+    noun: "CEP",
+    client: get_ceps, stream_ceps => get_cep,
+    example: r#"["01001000", "20040020"]"#,
+    key: batch_key => "CEPs repetidos, com ou sem máscara (`01001-000` e `01001000`), são consultados uma única vez, mas cada um tem a sua própria entrada no resultado.",
 }
 
 #[cfg(test)]
@@ -157,7 +319,9 @@ mod cep_tests {
             .await;
 
         let cep_service = CepService::new(&server.base_url());
-        let response = cep_service.get_cep_request(cep_code).await;
+        let response = cep_service
+            .get_cep_request(&cep_code.parse().unwrap(), CepVersion::V2)
+            .await;
         let expectation = response.unwrap_err();
 
         mock.assert_async().await;
@@ -190,7 +354,9 @@ mod cep_tests {
             .await;
 
         let cep_service = CepService::new(&server.base_url());
-        let response = cep_service.get_cep_request(cep_code).await;
+        let response = cep_service
+            .get_cep_request(&cep_code.parse().unwrap(), CepVersion::V2)
+            .await;
         let expectation = response.unwrap_err();
 
         mock.assert_async().await;
@@ -200,4 +366,111 @@ mod cep_tests {
             Some(StatusCode::INTERNAL_SERVER_ERROR.as_u16())
         );
     }
+
+    #[tokio::test]
+    async fn get_cep_decode_error() {
+        let cep_code = "99999997";
+        let server = MockServer::start_async().await;
+        server
+            .mock_async(|when, then| {
+                when.method("GET").path(format!("/api/cep/v2/{cep_code}"));
+                then.status(200).json_body(json!({
+                    "cep": cep_code,
+                    "state": 35,
+                }));
+            })
+            .await;
+
+        let client = BrasilApiClient::with_base_url(&server.base_url());
+        let expectation = client.get_cep(cep_code).await.unwrap_err();
+
+        assert_eq!(expectation.code, Some(StatusCode::OK.as_u16()));
+        match expectation.error {
+            Errored::Decode(decode) => {
+                assert_eq!(decode.path, "state");
+                assert!(decode.body.contains(cep_code));
+            }
+            error => panic!("unexpected error kind: {error:?}"),
+        }
+    }
+
+    #[test]
+    fn coordinates_accept_text_numbers_and_blanks() {
+        let cep: Cep = serde_json::from_value(json!({
+            "cep": "01001000",
+            "state": "SP",
+            "city": "São Paulo",
+            "neighborhood": "Sé",
+            "street": "Praça da Sé",
+            "service": "open-cep",
+            "location": {
+                "type": "Point",
+                "coordinates": { "longitude": "-46.6339", "latitude": -23.5503 }
+            }
+        }))
+        .unwrap();
+
+        let coordinates = cep.coordinates().unwrap();
+        assert_eq!(coordinates.longitude, Some(-46.6339));
+        assert_eq!(coordinates.latitude, Some(-23.5503));
+
+        let blank: Location = serde_json::from_value(json!({
+            "type": "Point",
+            "coordinates": { "longitude": "", "latitude": null }
+        }))
+        .unwrap();
+        assert_eq!(blank.coordinates, Coordinates::default());
+
+        let empty: Location = serde_json::from_value(json!({
+            "type": "Point",
+            "coordinates": {}
+        }))
+        .unwrap();
+        assert_eq!(empty.coordinates, Coordinates::default());
+    }
+
+    #[test]
+    fn distance_between_ceps() {
+        let sao_paulo = Coordinates {
+            latitude: Some(-23.5505),
+            longitude: Some(-46.6333),
+        };
+        let rio = Coordinates {
+            latitude: Some(-22.9068),
+            longitude: Some(-43.1729),
+        };
+
+        let distance = sao_paulo.distance_km(&rio).unwrap();
+        assert!((distance - 361.0).abs() < 2.0, "{distance}");
+        assert_eq!(sao_paulo.distance_km(&sao_paulo), Some(0.0));
+        assert_eq!(sao_paulo.distance_km(&Coordinates::default()), None);
+    }
+
+    #[tokio::test]
+    async fn get_cep_v1_skips_geolocation() {
+        let server = MockServer::start_async().await;
+        let v1 = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/cep/v1/01001000");
+                then.status(200).json_body(json!({
+                    "cep": "01001000",
+                    "state": "SP",
+                    "city": "São Paulo",
+                    "neighborhood": "Sé",
+                    "street": "Praça da Sé",
+                    "service": "viacep",
+                }));
+            })
+            .await;
+
+        let client = BrasilApiClient::with_base_url(&server.base_url());
+        let cep = client
+            .get_cep_with_version("01001-000", CepVersion::V1)
+            .await
+            .unwrap();
+
+        v1.assert_async().await;
+        assert_eq!(cep.service, CepProvider::ViaCep);
+        assert!(cep.location.is_none());
+    }
 }
@@ -0,0 +1,114 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// Provider de CEP que respondeu a consulta, informado no campo `service` da resposta.
+///
+/// Valores que esta versão da biblioteca não conhece ficam em [`CepProvider::Other`],
+/// então um provider novo na API não quebra a desserialização.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum CepProvider {
+    OpenCep,
+    Correios,
+    CorreiosAlt,
+    ViaCep,
+    Widenet,
+    Other(String),
+}
+
+impl CepProvider {
+    /// Nome do provider como retornado pela API (`open-cep`, `viacep`, ...).
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::OpenCep => "open-cep",
+            Self::Correios => "correios",
+            Self::CorreiosAlt => "correios-alt",
+            Self::ViaCep => "viacep",
+            Self::Widenet => "widenet",
+            Self::Other(name) => name,
+        }
+    }
+}
+
+impl From<String> for CepProvider {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "open-cep" => Self::OpenCep,
+            "correios" => Self::Correios,
+            "correios-alt" => Self::CorreiosAlt,
+            "viacep" => Self::ViaCep,
+            "widenet" => Self::Widenet,
+            _ => Self::Other(name),
+        }
+    }
+}
+
+impl From<CepProvider> for String {
+    fn from(provider: CepProvider) -> Self {
+        match provider {
+            CepProvider::Other(name) => name,
+            provider => provider.as_str().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for CepProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Versão do endpoint de CEP.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum CepVersion {
+    /// `/api/cep/v1`: mais rápida, sem geolocalização.
+    V1,
+    /// `/api/cep/v2`: inclui a geolocalização em [`Cep::location`](super::Cep::location)
+    /// quando disponível.
+    #[default]
+    V2,
+}
+
+impl CepVersion {
+    pub(crate) fn path(&self, cep_code: &super::CepCode) -> String {
+        match self {
+            Self::V1 => format!("/api/cep/v1/{}", cep_code.as_str()),
+            Self::V2 => format!("/api/cep/v2/{}", cep_code.as_str()),
+        }
+    }
+
+    /// Nome da operação, usado nos spans de tracing, middlewares e circuit breaker.
+    pub(crate) fn operation(&self) -> &'static str {
+        match self {
+            Self::V1 => "cep.get_v1",
+            Self::V2 => "cep.get",
+        }
+    }
+}
+
+#[cfg(test)]
+mod provider_tests {
+    use super::*;
+
+    #[test]
+    fn known_and_unknown_providers_round_trip() {
+        let providers: Vec<CepProvider> =
+            serde_json::from_str(r#"["open-cep", "viacep", "widenet", "brasilapi"]"#).unwrap();
+
+        assert_eq!(
+            providers,
+            [
+                CepProvider::OpenCep,
+                CepProvider::ViaCep,
+                CepProvider::Widenet,
+                CepProvider::Other("brasilapi".to_string()),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&providers).unwrap(),
+            r#"["open-cep","viacep","widenet","brasilapi"]"#
+        );
+        assert_eq!(CepProvider::CorreiosAlt.to_string(), "correios-alt");
+    }
+}
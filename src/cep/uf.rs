@@ -0,0 +1,216 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Cep, IntoCepCode};
+use crate::error::{Error, Errored};
+
+/// Região do Brasil, com a mesma numeração e siglas do IBGE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Region {
+    Norte,
+    Nordeste,
+    Sudeste,
+    Sul,
+    CentroOeste,
+}
+
+impl Region {
+    /// Código da região no IBGE.
+    pub fn id(&self) -> i32 {
+        match self {
+            Self::Norte => 1,
+            Self::Nordeste => 2,
+            Self::Sudeste => 3,
+            Self::Sul => 4,
+            Self::CentroOeste => 5,
+        }
+    }
+
+    pub fn sigla(&self) -> &'static str {
+        match self {
+            Self::Norte => "N",
+            Self::Nordeste => "NE",
+            Self::Sudeste => "SE",
+            Self::Sul => "S",
+            Self::CentroOeste => "CO",
+        }
+    }
+
+    pub fn nome(&self) -> &'static str {
+        match self {
+            Self::Norte => "Norte",
+            Self::Nordeste => "Nordeste",
+            Self::Sudeste => "Sudeste",
+            Self::Sul => "Sul",
+            Self::CentroOeste => "Centro-Oeste",
+        }
+    }
+}
+
+#[cfg(feature = "ibge")]
+impl From<Region> for crate::ibge::StateRegion {
+    fn from(region: Region) -> Self {
+        Self {
+            id: region.id(),
+            sigla: region.sigla().to_string(),
+            nome: region.nome().to_string(),
+        }
+    }
+}
+
+/// Estado de um CEP, resolvido a partir das faixas de CEP dos Correios.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct CepState {
+    /// Sigla do estado, a mesma usada pelo IBGE (`SP`, `DF`, ...).
+    pub uf: &'static str,
+    pub region: Region,
+}
+
+/// Faixas de prefixos de 5 dígitos de cada estado, em ordem.
+const RANGES: &[(u32, u32, &str, Region)] = &[
+    (1000, 19999, "SP", Region::Sudeste),
+    (20000, 28999, "RJ", Region::Sudeste),
+    (29000, 29999, "ES", Region::Sudeste),
+    (30000, 39999, "MG", Region::Sudeste),
+    (40000, 48999, "BA", Region::Nordeste),
+    (49000, 49999, "SE", Region::Nordeste),
+    (50000, 56999, "PE", Region::Nordeste),
+    (57000, 57999, "AL", Region::Nordeste),
+    (58000, 58999, "PB", Region::Nordeste),
+    (59000, 59999, "RN", Region::Nordeste),
+    (60000, 63999, "CE", Region::Nordeste),
+    (64000, 64999, "PI", Region::Nordeste),
+    (65000, 65999, "MA", Region::Nordeste),
+    (66000, 68899, "PA", Region::Norte),
+    (68900, 68999, "AP", Region::Norte),
+    (69000, 69299, "AM", Region::Norte),
+    (69300, 69399, "RR", Region::Norte),
+    (69400, 69899, "AM", Region::Norte),
+    (69900, 69999, "AC", Region::Norte),
+    (70000, 72799, "DF", Region::CentroOeste),
+    (72800, 72999, "GO", Region::CentroOeste),
+    (73000, 73699, "DF", Region::CentroOeste),
+    (73700, 76799, "GO", Region::CentroOeste),
+    (76800, 76999, "RO", Region::Norte),
+    (77000, 77999, "TO", Region::Norte),
+    (78000, 78899, "MT", Region::CentroOeste),
+    (79000, 79999, "MS", Region::CentroOeste),
+    (80000, 87999, "PR", Region::Sul),
+    (88000, 89999, "SC", Region::Sul),
+    (90000, 99999, "RS", Region::Sul),
+];
+
+/// #### `resolve_state(cep_code: impl IntoCepCode)`
+/// Resolve o estado e a região de um CEP sem fazer requisições, a partir das faixas de
+/// CEP de cada estado definidas pelos Correios.
+///
+/// ### Argumento
+/// * `cep_code:impl IntoCepCode` => CEP, com ou sem máscara.
+///
+/// ### Retorno
+/// * `Result<CepState, Error>`, com [`Errored::InvalidInput`] para CEPs malformados e
+///   [`Errored::NotFound`] para CEPs fora das faixas dos Correios.
+///
+/// # Exemplo
+/// ```rust
+/// use brasilapi::cep::{self, Region};
+///
+/// let state = cep::resolve_state("70040-010").unwrap();
+///
+/// assert_eq!(state.uf, "DF");
+/// assert_eq!(state.region, Region::CentroOeste);
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn resolve_state(cep_code: impl IntoCepCode) -> Result<CepState, Error> {
+    let cep_code = cep_code.into_cep_code()?;
+    let prefix: u32 = cep_code
+        .prefix()
+        .parse()
+        .expect("a CepCode always has 8 digits");
+
+    RANGES
+        .iter()
+        .find(|(start, end, _, _)| (*start..=*end).contains(&prefix))
+        .map(|&(_, _, uf, region)| CepState { uf, region })
+        .ok_or_else(|| {
+            Error::new(
+                format!("CEP {cep_code} is not in any state range"),
+                Errored::NotFound,
+                None,
+            )
+        })
+}
+
+impl Cep {
+    /// Verifica se o `state` retornado pela API corresponde ao estado esperado para o
+    /// CEP, segundo as faixas dos Correios. Veja [`resolve_state`].
+    ///
+    /// Retorna `false` quando o CEP não pode ser resolvido, o que também indica uma
+    /// resposta suspeita.
+    pub fn has_consistent_state(&self) -> bool {
+        resolve_state(self.cep.as_str()).is_ok_and(|state| state.uf == self.state)
+    }
+}
+
+#[cfg(test)]
+mod uf_tests {
+    use super::*;
+    use crate::cep::CepProvider;
+
+    #[test]
+    fn resolves_every_state_range() {
+        let cases = [
+            ("01001000", "SP"),
+            ("20040020", "RJ"),
+            ("29000000", "ES"),
+            ("30130010", "MG"),
+            ("40010000", "BA"),
+            ("64000000", "PI"),
+            ("68900000", "AP"),
+            ("69301000", "RR"),
+            ("69400000", "AM"),
+            ("69900000", "AC"),
+            ("70040010", "DF"),
+            ("72800000", "GO"),
+            ("73000000", "DF"),
+            ("76800000", "RO"),
+            ("77000000", "TO"),
+            ("78899999", "MT"),
+            ("90010000", "RS"),
+        ];
+
+        for (cep, uf) in cases {
+            assert_eq!(resolve_state(cep).unwrap().uf, uf, "{cep}");
+        }
+
+        assert_eq!(resolve_state("88000-000").unwrap().region, Region::Sul);
+    }
+
+    #[test]
+    fn unknown_and_malformed_ceps_are_errors() {
+        assert_eq!(
+            resolve_state("00999999").unwrap_err().error,
+            Errored::NotFound
+        );
+        assert_eq!(
+            resolve_state("123").unwrap_err().error,
+            Errored::InvalidInput
+        );
+    }
+
+    #[test]
+    fn cross_checks_api_state() {
+        let mut cep = Cep {
+            cep: "01001000".to_string(),
+            state: "SP".to_string(),
+            city: "São Paulo".to_string(),
+            neighborhood: "Sé".to_string(),
+            street: "Praça da Sé".to_string(),
+            service: CepProvider::OpenCep,
+            location: None,
+        };
+        assert!(cep.has_consistent_state());
+
+        cep.state = "RJ".to_string();
+        assert!(!cep.has_consistent_state());
+    }
+}
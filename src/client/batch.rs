@@ -0,0 +1,348 @@
+use std::{collections::HashMap, future::Future};
+
+use futures_util::{stream, Stream, StreamExt};
+
+use crate::error::Error;
+
+/// Executa `lookup` para cada código distinto de `codes`, com no máximo `concurrency`
+/// consultas ao mesmo tempo.
+///
+/// Os resultados saem na ordem em que as consultas terminam, junto com o código
+/// informado, e códigos repetidos são consultados uma única vez.
+#[cfg(any(feature = "cnpj", feature = "ddd", feature = "isbn"))]
+pub(crate) fn stream<'a, I, V, F, Fut>(
+    codes: I,
+    concurrency: usize,
+    lookup: F,
+) -> impl Stream<Item = (String, Result<V, Error>)> + 'a
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+    F: Fn(String) -> Fut + 'a,
+    Fut: Future<Output = Result<V, Error>> + 'a,
+    V: 'a,
+{
+    let mut seen = std::collections::HashSet::new();
+    let codes: Vec<String> = codes
+        .into_iter()
+        .map(Into::into)
+        .filter(|code| seen.insert(code.clone()))
+        .collect();
+
+    stream::iter(codes)
+        .map(move |code| {
+            let lookup = lookup(code.clone());
+            async move { (code, lookup.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+}
+
+/// Como [`stream`], mas consulta uma única vez os códigos que `key` normaliza para a
+/// mesma chave (um CEP com e sem máscara, por exemplo).
+///
+/// `lookup` recebe a chave, e o resultado é repetido para cada código distinto
+/// informado, então cada um continua tendo a sua própria entrada.
+#[cfg(feature = "cep")]
+pub(crate) fn stream_by_key<'a, I, V, K, F, Fut>(
+    codes: I,
+    concurrency: usize,
+    key: K,
+    lookup: F,
+) -> impl Stream<Item = (String, Result<V, Error>)> + 'a
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+    K: Fn(&str) -> String,
+    F: Fn(String) -> Fut + 'a,
+    Fut: Future<Output = Result<V, Error>> + 'a,
+    V: Clone + 'a,
+{
+    let mut groups: Vec<(String, Vec<String>)> = Vec::new();
+    let mut positions = HashMap::new();
+    for code in codes {
+        let code = code.into();
+        let position = *positions.entry(key(&code)).or_insert_with_key(|key| {
+            groups.push((key.clone(), Vec::new()));
+            groups.len() - 1
+        });
+
+        let inputs = &mut groups[position].1;
+        if !inputs.contains(&code) {
+            inputs.push(code);
+        }
+    }
+
+    stream::iter(groups)
+        .map(move |(key, inputs)| {
+            let lookup = lookup(key);
+            async move { (inputs, lookup.await) }
+        })
+        .buffer_unordered(concurrency.max(1))
+        .flat_map(|(inputs, result)| {
+            stream::iter(inputs.into_iter().map(move |code| (code, result.clone())))
+        })
+}
+
+/// Aguarda todas as consultas de [`stream`] e retorna os resultados por código.
+pub(crate) async fn collect<V>(
+    results: impl Stream<Item = (String, Result<V, Error>)>,
+) -> HashMap<String, Result<V, Error>> {
+    results.collect().await
+}
+
+/// Gera as consultas em lote de um módulo a partir da sua consulta unitária: os métodos
+/// `get_*s` e `stream_*s` do [`BrasilApiClient`](super::BrasilApiClient) e as funções
+/// livres `get_many` e `stream_many`.
+///
+/// Os resultados usam como chave o código exatamente como informado. Com `key`, códigos
+/// que ela normaliza para o mesmo valor são consultados uma única vez (veja
+/// [`stream_by_key`]).
+macro_rules! batch_lookups {
+    (
+        module: $module:ident,
+        item: $item:ty,
+        noun: $noun:literal,
+        client: $get_all:ident, $stream_all:ident => $get_one:ident,
+        example: $example:literal $(,)?
+    ) => {
+        $crate::client::batch::batch_lookups! {
+            @impl
+            module: $module,
+            item: $item,
+            noun: $noun,
+            client: $get_all, $stream_all => $get_one,
+            example: $example,
+            stream: stream(),
+            dedup_doc: concat!($noun, "s repetidos são consultados uma única vez."),
+        }
+    };
+    (
+        module: $module:ident,
+        item: $item:ty,
+        noun: $noun:literal,
+        client: $get_all:ident, $stream_all:ident => $get_one:ident,
+        example: $example:literal,
+        key: $key:expr => $dedup_doc:expr $(,)?
+    ) => {
+        $crate::client::batch::batch_lookups! {
+            @impl
+            module: $module,
+            item: $item,
+            noun: $noun,
+            client: $get_all, $stream_all => $get_one,
+            example: $example,
+            stream: stream_by_key($key),
+            dedup_doc: $dedup_doc,
+        }
+    };
+    (
+        @impl
+        module: $module:ident,
+        item: $item:ty,
+        noun: $noun:literal,
+        client: $get_all:ident, $stream_all:ident => $get_one:ident,
+        example: $example:literal,
+        stream: $stream:ident($($key:expr)?),
+        dedup_doc: $dedup_doc:expr,
+    ) => {
+        impl<T: $crate::client::Transport> $crate::client::BrasilApiClient<T> {
+            #[doc = concat!("Busca vários ", $noun, "s utilizando este cliente, com no máximo `concurrency`")]
+            /// consultas ao mesmo tempo. Veja [`get_many`].
+            pub async fn $get_all<I>(
+                &self,
+                codes: I,
+                concurrency: usize,
+            ) -> ::std::collections::HashMap<String, Result<$item, $crate::error::Error>>
+            where
+                I: IntoIterator,
+                I::Item: Into<String>,
+            {
+                $crate::client::batch::collect(self.$stream_all(codes, concurrency)).await
+            }
+
+            #[doc = concat!("Versão em `Stream` de [`", stringify!($get_all), "`](Self::", stringify!($get_all), "). Veja [`stream_many`].")]
+            pub fn $stream_all<I>(
+                &self,
+                codes: I,
+                concurrency: usize,
+            ) -> impl ::futures_util::Stream<Item = (String, Result<$item, $crate::error::Error>)> + '_
+            where
+                I: IntoIterator,
+                I::Item: Into<String>,
+            {
+                $crate::client::batch::$stream(codes, concurrency, $($key,)? move |code| async move {
+                    self.$get_one(&code).await
+                })
+            }
+        }
+
+        /// #### `get_many(codes: impl IntoIterator, concurrency: usize)`
+        #[doc = concat!("Busca vários ", $noun, "s, com no máximo `concurrency` consultas ao mesmo tempo.")]
+        ///
+        #[doc = $dedup_doc]
+        ///
+        /// ### Argumentos
+        #[doc = concat!("* `codes` => ", $noun, "s para consulta.")]
+        /// * `concurrency:usize` => Número máximo de consultas ao mesmo tempo.
+        ///
+        /// ### Retorno
+        #[doc = concat!("* `HashMap<String, Result<", stringify!($item), ", Error>>`, tendo como chave cada ", $noun, " exatamente como informado.")]
+        ///
+        /// # Exemplo
+        /// ```rust,no_run
+        #[doc = concat!("use brasilapi::", stringify!($module), ";")]
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        #[doc = concat!("    let results = ", stringify!($module), "::get_many(", $example, ", 10).await;")]
+        /// }
+        /// ```
+        pub async fn get_many<I>(
+            codes: I,
+            concurrency: usize,
+        ) -> ::std::collections::HashMap<String, Result<$item, $crate::error::Error>>
+        where
+            I: IntoIterator,
+            I::Item: Into<String>,
+        {
+            $crate::client::default_client().$get_all(codes, concurrency).await
+        }
+
+        /// #### `stream_many(codes: impl IntoIterator, concurrency: usize)`
+        /// Versão em `Stream` de [`get_many`]: cada resultado é entregue assim que a consulta
+        /// termina, útil para gravar a saída aos poucos.
+        ///
+        /// # Exemplo
+        /// ```rust,no_run
+        /// use futures::StreamExt;
+        #[doc = concat!("use brasilapi::", stringify!($module), ";")]
+        ///
+        /// #[tokio::main]
+        /// async fn main() {
+        #[doc = concat!("    let mut results = std::pin::pin!(", stringify!($module), "::stream_many(", $example, ", 10));")]
+        ///
+        ///     while let Some((code, result)) = results.next().await {
+        ///         println!("{}: {:?}", code, result.is_ok());
+        ///     }
+        /// }
+        /// ```
+        pub fn stream_many<I>(
+            codes: I,
+            concurrency: usize,
+        ) -> impl ::futures_util::Stream<Item = (String, Result<$item, $crate::error::Error>)>
+        where
+            I: IntoIterator,
+            I::Item: Into<String>,
+        {
+            $crate::client::default_client().$stream_all(codes, concurrency)
+        }
+    };
+}
+
+pub(crate) use batch_lookups;
+
+#[cfg(test)]
+mod batch_tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::error::Errored;
+
+    #[cfg(any(feature = "cnpj", feature = "ddd", feature = "isbn"))]
+    #[tokio::test]
+    async fn lookups_are_deduplicated_and_bounded() {
+        use std::time::Duration;
+
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+        let calls = AtomicUsize::new(0);
+
+        let codes = ["1", "2", "3", "1", "4", "5", "2", "6"];
+        let results = collect(stream(codes, 2, |code| {
+            let (in_flight, max_in_flight, calls) = (&in_flight, &max_in_flight, &calls);
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+                let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+                crate::client::sleep(Duration::from_millis(5)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+
+                match code.as_str() {
+                    "4" => Err(Error::from_status(404, String::new())),
+                    _ => Ok(code.len()),
+                }
+            }
+        }))
+        .await;
+
+        assert_eq!(results.len(), 6);
+        assert_eq!(calls.load(Ordering::SeqCst), 6);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+        assert_eq!(results["1"], Ok(1));
+        assert_eq!(results["4"].as_ref().unwrap_err().error, Errored::NotFound);
+    }
+
+    #[cfg(feature = "cep")]
+    #[tokio::test]
+    async fn keyed_lookups_share_one_call_per_key() {
+        let calls = AtomicUsize::new(0);
+
+        let codes = ["a", "A", "b", "a"];
+        let lookup = |key: String| {
+            let calls = &calls;
+            async move {
+                calls.fetch_add(1, Ordering::SeqCst);
+
+                match key.as_str() {
+                    "b" => Err(Error::from_status(404, String::new())),
+                    _ => Ok(key),
+                }
+            }
+        };
+        let results = collect(stream_by_key(codes, 2, str::to_lowercase, lookup)).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(results["a"], Ok("a".to_string()));
+        assert_eq!(results["A"], Ok("a".to_string()));
+        assert_eq!(results["b"].as_ref().unwrap_err().error, Errored::NotFound);
+    }
+
+    #[cfg(all(feature = "cep", feature = "ddd"))]
+    #[tokio::test]
+    async fn client_batches_return_results_by_input() {
+        use crate::client::{BrasilApiClient, InMemoryTransport};
+
+        let transport = InMemoryTransport::new()
+            .route(
+                "/api/cep/v2/01001000",
+                200,
+                r#"{"cep": "01001000", "state": "SP", "city": "São Paulo", "neighborhood": "Sé", "street": "Praça da Sé", "service": "open-cep"}"#,
+            )
+            .route("/api/ddd/v1/61", 200, r#"{"state": "DF", "cities": []}"#);
+        let client = BrasilApiClient::with_transport(transport.clone());
+
+        let ceps = client
+            .get_ceps(["01001000", "99999999", "01001-000", "abc"], 4)
+            .await;
+
+        assert_eq!(ceps.len(), 4);
+        assert_eq!(
+            ceps["abc"].as_ref().unwrap_err().error,
+            Errored::InvalidInput
+        );
+        assert_eq!(ceps["01001000"].as_ref().unwrap().state, "SP");
+        assert_eq!(ceps["01001-000"].as_ref().unwrap().state, "SP");
+        assert!(ceps["99999999"].as_ref().unwrap_err().is_not_found());
+
+        let ddds: Vec<_> = client
+            .stream_ddds(vec!["61".to_string()], 1)
+            .collect()
+            .await;
+
+        assert_eq!(ddds.len(), 1);
+        assert_eq!(ddds[0].0, "61");
+        assert_eq!(transport.requests().len(), 3);
+    }
+}
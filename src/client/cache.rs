@@ -0,0 +1,405 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use web_time::SystemTime;
+
+use super::Response;
+use crate::error::Error;
+
+/// Resposta armazenada em um [`CacheStore`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// Status HTTP da resposta (200, ou 404 para cache negativo).
+    pub status: u16,
+    pub body: String,
+    pub expires_at: SystemTime,
+}
+
+impl CacheEntry {
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires_at <= now
+    }
+}
+
+/// Armazenamento utilizado pelo [`Cache`].
+///
+/// O armazenamento não precisa descartar entradas expiradas: o [`Cache`] verifica a
+/// validade de cada entrada ao consultá-la, e entradas expiradas continuam disponíveis
+/// para o modo offline até serem substituídas.
+pub trait CacheStore: Send + Sync {
+    fn get(&self, key: &str) -> Option<CacheEntry>;
+    fn insert(&self, key: &str, entry: CacheEntry);
+    fn remove(&self, key: &str);
+    fn clear(&self);
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Armazenamento em memória com limite de entradas, descartando a menos usada (LRU).
+#[derive(Debug)]
+pub struct MemoryStore {
+    max_entries: usize,
+    state: Mutex<MemoryState>,
+    evictions: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    tick: u64,
+    entries: HashMap<String, (CacheEntry, u64)>,
+    recency: BTreeMap<u64, String>,
+}
+
+impl MemoryStore {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries: max_entries.max(1),
+            state: Mutex::new(MemoryState::default()),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    /// Quantidade de entradas descartadas por falta de espaço.
+    pub fn evictions(&self) -> u64 {
+        self.evictions.load(Ordering::Relaxed)
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, MemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl MemoryState {
+    fn touch(&mut self, key: &str) -> Option<&CacheEntry> {
+        self.tick += 1;
+        let tick = self.tick;
+
+        let (entry, last_used) = self.entries.get_mut(key)?;
+        self.recency.remove(last_used);
+        self.recency.insert(tick, key.to_string());
+        *last_used = tick;
+
+        Some(entry)
+    }
+}
+
+impl CacheStore for MemoryStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        self.state().touch(key).cloned()
+    }
+
+    fn insert(&self, key: &str, entry: CacheEntry) {
+        let mut state = self.state();
+        state.tick += 1;
+        let tick = state.tick;
+
+        if let Some((_, last_used)) = state.entries.insert(key.to_string(), (entry, tick)) {
+            state.recency.remove(&last_used);
+        }
+        state.recency.insert(tick, key.to_string());
+
+        while state.entries.len() > self.max_entries {
+            let Some((_, oldest)) = state.recency.pop_first() else {
+                break;
+            };
+            state.entries.remove(&oldest);
+            self.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let mut state = self.state();
+
+        if let Some((_, last_used)) = state.entries.remove(key) {
+            state.recency.remove(&last_used);
+        }
+    }
+
+    fn clear(&self) {
+        let mut state = self.state();
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    fn len(&self) -> usize {
+        self.state().entries.len()
+    }
+}
+
+/// Estatísticas de uso do [`Cache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    /// Acertos que retornaram um 404 armazenado.
+    pub negative_hits: u64,
+    pub entries: usize,
+}
+
+/// Cache de respostas do cliente.
+///
+/// Cada rota pode ter o seu próprio tempo de vida (TTL), definido por prefixo; rotas sem
+/// TTL próprio usam o TTL padrão. Respostas 404 também podem ser armazenadas (cache
+/// negativo), de forma que consultas repetidas a um CEP inexistente não voltem à API.
+///
+/// # Exemplo
+/// ```rust
+/// use std::time::Duration;
+/// use brasilapi::client::{BrasilApiClient, Cache};
+///
+/// let client = BrasilApiClient::builder()
+///     .cache(
+///         Cache::new(10_000)
+///             .ttl(Duration::from_secs(60 * 60))
+///             .endpoint_ttl("/api/banks/v1", Duration::from_secs(24 * 60 * 60))
+///             .endpoint_ttl("/api/taxas/v1", Duration::from_secs(10 * 60))
+///             .negative_ttl(Duration::from_secs(5 * 60)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+pub struct Cache {
+    store: Arc<dyn CacheStore>,
+    ttl: Duration,
+    endpoint_ttls: Vec<(String, Duration)>,
+    negative_ttl: Option<Duration>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    negative_hits: AtomicU64,
+}
+
+impl fmt::Debug for Cache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cache")
+            .field("ttl", &self.ttl)
+            .field("endpoint_ttls", &self.endpoint_ttls)
+            .field("negative_ttl", &self.negative_ttl)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl Cache {
+    /// Cache em memória com até `max_entries` respostas e TTL padrão de 5 minutos.
+    pub fn new(max_entries: usize) -> Self {
+        Self::with_store(MemoryStore::new(max_entries))
+    }
+
+    /// Cache utilizando um [`CacheStore`] próprio.
+    pub fn with_store<S: CacheStore + 'static>(store: S) -> Self {
+        Self {
+            store: Arc::new(store),
+            ttl: Duration::from_secs(5 * 60),
+            endpoint_ttls: Vec::new(),
+            negative_ttl: None,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            negative_hits: AtomicU64::new(0),
+        }
+    }
+
+    /// TTL padrão das respostas.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// TTL das rotas que começam com `prefix`. Um TTL zero desabilita o cache da rota.
+    pub fn endpoint_ttl(mut self, prefix: &str, ttl: Duration) -> Self {
+        self.endpoint_ttls.push((prefix.to_string(), ttl));
+        self
+    }
+
+    /// Habilita o cache de respostas 404 pelo tempo informado.
+    pub fn negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = Some(ttl);
+        self
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            negative_hits: self.negative_hits.load(Ordering::Relaxed),
+            entries: self.store.len(),
+        }
+    }
+
+    /// Remove todas as respostas armazenadas.
+    pub fn clear(&self) {
+        self.store.clear();
+    }
+
+    fn ttl_for(&self, path: &str) -> Duration {
+        self.endpoint_ttls
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, ttl)| *ttl)
+            .unwrap_or(self.ttl)
+    }
+
+    /// Busca uma resposta válida para `path`.
+    pub(crate) fn lookup(&self, path: &str) -> Option<Result<Response, Error>> {
+        let now = SystemTime::now();
+
+        let entry = self.store.get(path).filter(|entry| !entry.is_expired(now));
+
+        self.record(entry)
+    }
+
+    /// Busca qualquer resposta armazenada para `path`, mesmo expirada.
+    pub(crate) fn lookup_stale(&self, path: &str) -> Option<Result<Response, Error>> {
+        self.record(self.store.get(path))
+    }
+
+    fn record(&self, entry: Option<CacheEntry>) -> Option<Result<Response, Error>> {
+        let Some(entry) = entry else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return None;
+        };
+
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        if entry.status != 200 {
+            self.negative_hits.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Some(entry_to_result(entry))
+    }
+
+    /// Armazena o resultado de uma requisição, quando ele pode ser armazenado.
+    pub(crate) fn update(&self, path: &str, result: &Result<Response, Error>) {
+        let (status, body, ttl) = match result {
+            Ok(response) => (response.status, response.body.clone(), self.ttl_for(path)),
+            Err(error) if error.is_not_found() => match self.negative_ttl {
+                Some(ttl) => (404, error.message.clone(), ttl),
+                None => return,
+            },
+            Err(_) => return,
+        };
+
+        if ttl.is_zero() {
+            return;
+        }
+
+        let entry = CacheEntry {
+            status,
+            body,
+            expires_at: SystemTime::now() + ttl,
+        };
+
+        self.store.insert(path, entry);
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn entry_to_result(entry: CacheEntry) -> Result<Response, Error> {
+    if entry.status == 200 {
+        Ok(Response {
+            status: entry.status,
+            body: entry.body,
+        })
+    } else {
+        Err(Error::from_status(entry.status, entry.body))
+    }
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+
+    fn entry(body: &str) -> CacheEntry {
+        CacheEntry {
+            status: 200,
+            body: body.to_string(),
+            expires_at: SystemTime::now() + Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn memory_store_evicts_least_recently_used() {
+        let store = MemoryStore::new(2);
+
+        store.insert("a", entry("a"));
+        store.insert("b", entry("b"));
+        store.get("a");
+        store.insert("c", entry("c"));
+
+        assert!(store.get("a").is_some());
+        assert!(store.get("b").is_none());
+        assert!(store.get("c").is_some());
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.evictions(), 1);
+    }
+
+    #[test]
+    fn expired_entries_are_misses() {
+        let cache = Cache::new(10).endpoint_ttl("/api/taxas/v1", Duration::ZERO);
+        let response = Ok(Response {
+            status: 200,
+            body: "[]".to_string(),
+        });
+
+        cache.update("/api/taxas/v1", &response);
+        cache.store.insert(
+            "/api/banks/v1",
+            CacheEntry {
+                expires_at: SystemTime::now() - Duration::from_secs(1),
+                ..entry("[]")
+            },
+        );
+
+        assert!(cache.lookup("/api/taxas/v1").is_none());
+        assert!(cache.lookup("/api/banks/v1").is_none());
+        assert_eq!(cache.stats().misses, 2);
+
+        // Entradas expiradas continuam disponíveis para o modo offline.
+        assert!(cache.lookup_stale("/api/banks/v1").is_some());
+        assert!(cache.lookup_stale("/api/taxas/v1").is_none());
+    }
+
+    #[test]
+    fn not_found_is_cached_only_with_negative_ttl() {
+        let not_found = Err(Error::from_status(404, "{}".to_string()));
+
+        let cache = Cache::new(10);
+        cache.update("/api/cep/v2/00000000", &not_found);
+        assert!(cache.lookup("/api/cep/v2/00000000").is_none());
+
+        let cache = Cache::new(10).negative_ttl(Duration::from_secs(60));
+        cache.update("/api/cep/v2/00000000", &not_found);
+        let cached = cache.lookup("/api/cep/v2/00000000").unwrap();
+
+        assert!(cached.unwrap_err().is_not_found());
+        assert_eq!(cache.stats().negative_hits, 1);
+    }
+
+    #[test]
+    fn longest_prefix_ttl_wins() {
+        let cache = Cache::new(10)
+            .ttl(Duration::from_secs(1))
+            .endpoint_ttl("/api/ibge", Duration::from_secs(2))
+            .endpoint_ttl("/api/ibge/uf/v1", Duration::from_secs(3));
+
+        assert_eq!(cache.ttl_for("/api/ibge/uf/v1/SP"), Duration::from_secs(3));
+        assert_eq!(
+            cache.ttl_for("/api/ibge/municipios/v1/SP"),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            cache.ttl_for("/api/cep/v2/01001000"),
+            Duration::from_secs(1)
+        );
+    }
+}
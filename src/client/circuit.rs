@@ -0,0 +1,227 @@
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use web_time::Instant;
+
+use super::mirrors::is_server_failure;
+use crate::error::{Error, Errored};
+
+/// Estado do circuito de uma família de endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// As requisições são enviadas normalmente.
+    Closed,
+    /// As requisições falham imediatamente com [`Errored::CircuitOpen`].
+    Open,
+    /// O tempo de abertura acabou e uma requisição de teste decide se o circuito fecha
+    /// ou abre novamente.
+    HalfOpen,
+}
+
+/// Circuit breaker do cliente, com um circuito para cada família de endpoints (`cep`,
+/// `cnpj`, `fipe`, `isbn`, ...).
+///
+/// Depois de `failure_threshold` falhas seguidas (erros de conexão, timeouts ou 5xx) o
+/// circuito da família abre e as requisições para ela retornam [`Errored::CircuitOpen`]
+/// sem chegar à API. Passado o `open_duration`, uma única requisição de teste é enviada:
+/// se tiver sucesso o circuito fecha, senão abre novamente.
+///
+/// # Exemplo
+/// ```rust
+/// use std::time::Duration;
+/// use brasilapi::client::{BrasilApiClient, CircuitBreaker};
+///
+/// let client = BrasilApiClient::builder()
+///     .circuit_breaker(
+///         CircuitBreaker::new()
+///             .failure_threshold(3)
+///             .open_duration(Duration::from_secs(60)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    open_duration: Duration,
+    circuits: Mutex<HashMap<String, Circuit>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Circuit {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { probe_started: Instant },
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CircuitBreaker {
+    /// Abre após 5 falhas seguidas e permanece aberto por 30s.
+    pub fn new() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(30),
+            circuits: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Número de falhas seguidas que abre o circuito.
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    /// Tempo que o circuito fica aberto antes da requisição de teste.
+    pub fn open_duration(mut self, duration: Duration) -> Self {
+        self.open_duration = duration;
+        self
+    }
+
+    /// Estado atual do circuito de `family`.
+    pub fn state(&self, family: &str) -> CircuitState {
+        match self.lock().get(family) {
+            None | Some(Circuit::Closed { .. }) => CircuitState::Closed,
+            Some(Circuit::Open { until }) if *until > Instant::now() => CircuitState::Open,
+            Some(_) => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Verifica se uma requisição para `family` pode ser enviada.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn acquire(&self, family: &str, now: Instant) -> Result<(), Error> {
+        let mut circuits = self.lock();
+        let circuit = circuits
+            .entry(family.to_string())
+            .or_insert(Circuit::Closed { failures: 0 });
+
+        match *circuit {
+            Circuit::Closed { .. } => Ok(()),
+            Circuit::Open { until } if until <= now => {
+                *circuit = Circuit::HalfOpen { probe_started: now };
+                Ok(())
+            }
+            // Uma requisição de teste cancelada não pode deixar o circuito preso.
+            Circuit::HalfOpen { probe_started } if probe_started + self.open_duration <= now => {
+                *circuit = Circuit::HalfOpen { probe_started: now };
+                Ok(())
+            }
+            Circuit::Open { .. } | Circuit::HalfOpen { .. } => Err(Error::new(
+                format!("circuit breaker for {family} is open"),
+                Errored::CircuitOpen,
+                None,
+            )),
+        }
+    }
+
+    /// Registra o resultado de uma requisição para `family`.
+    pub(crate) fn report<T>(&self, family: &str, result: &Result<T, Error>, now: Instant) {
+        let mut circuits = self.lock();
+        let Some(circuit) = circuits.get_mut(family) else {
+            return;
+        };
+
+        let failed = matches!(result, Err(error) if is_server_failure(error));
+
+        *circuit = match (*circuit, failed) {
+            (_, false) => Circuit::Closed { failures: 0 },
+            (Circuit::Closed { failures }, true) if failures + 1 < self.failure_threshold => {
+                Circuit::Closed {
+                    failures: failures + 1,
+                }
+            }
+            (_, true) => Circuit::Open {
+                until: now + self.open_duration,
+            },
+        };
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Circuit>> {
+        self.circuits.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Família de endpoints de uma operação: `cnpj` para `cnpj.get`.
+pub(crate) fn family(operation: &str) -> &str {
+    operation.split('.').next().unwrap_or(operation)
+}
+
+#[cfg(test)]
+mod circuit_tests {
+    use super::*;
+    use crate::client::{BrasilApiClient, InMemoryTransport};
+
+    #[test]
+    fn circuit_opens_after_threshold_and_half_opens_after_duration() {
+        let breaker = CircuitBreaker::new()
+            .failure_threshold(2)
+            .open_duration(Duration::from_secs(10));
+        let now = Instant::now();
+        let failure: Result<(), Error> = Err(Error::from_status(503, String::new()));
+
+        for _ in 0..2 {
+            breaker.acquire("cnpj", now).unwrap();
+            breaker.report("cnpj", &failure, now);
+        }
+
+        assert_eq!(
+            breaker.acquire("cnpj", now).unwrap_err().error,
+            Errored::CircuitOpen
+        );
+        assert!(breaker.acquire("cep", now).is_ok());
+
+        // Só uma requisição de teste passa enquanto o circuito está meio aberto.
+        let later = now + Duration::from_secs(10);
+        breaker.acquire("cnpj", later).unwrap();
+        assert!(breaker.acquire("cnpj", later).is_err());
+
+        breaker.report("cnpj", &failure, later);
+        assert!(breaker.acquire("cnpj", later).is_err());
+
+        let even_later = later + Duration::from_secs(10);
+        breaker.acquire("cnpj", even_later).unwrap();
+        breaker.report("cnpj", &Ok(()), even_later);
+        assert!(breaker.acquire("cnpj", even_later).is_ok());
+    }
+
+    #[test]
+    fn not_found_does_not_count_as_failure() {
+        let breaker = CircuitBreaker::new().failure_threshold(1);
+        let now = Instant::now();
+
+        breaker.acquire("cep", now).unwrap();
+        breaker.report::<()>("cep", &Err(Error::from_status(404, String::new())), now);
+
+        assert_eq!(breaker.state("cep"), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn client_short_circuits_open_family() {
+        let transport = InMemoryTransport::new()
+            .route("/api/cnpj/v1/00000000000191", 500, "Internal Server Error")
+            .route("/api/ddd/v1/61", 200, r#"{"state": "DF", "cities": []}"#);
+        let client = BrasilApiClient::builder()
+            .circuit_breaker(CircuitBreaker::new().failure_threshold(2))
+            .build_with_transport(transport.clone());
+
+        for _ in 0..2 {
+            client
+                .get("cnpj.get", "/api/cnpj/v1/00000000000191")
+                .await
+                .unwrap_err();
+        }
+        let error = client
+            .get("cnpj.get", "/api/cnpj/v1/00000000000191")
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error, Errored::CircuitOpen);
+        assert_eq!(client.circuit_state("cnpj"), Some(CircuitState::Open));
+        assert!(client.get("ddd.get", "/api/ddd/v1/61").await.is_ok());
+        // Duas requisições para o CNPJ antes de o circuito abrir, e uma para o DDD.
+        assert_eq!(transport.requests().len(), 3);
+    }
+}
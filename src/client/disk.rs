@@ -0,0 +1,246 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+use super::cache::{CacheEntry, CacheStore};
+
+/// Tamanho máximo do nome de um arquivo do cache, sem a extensão. Fica abaixo do limite
+/// de 255 bytes dos sistemas de arquivos comuns, com folga para o arquivo temporário.
+const MAX_FILE_NAME: usize = 200;
+
+/// Armazenamento em disco: um arquivo JSON por resposta dentro de um diretório.
+///
+/// As respostas sobrevivem ao reinício da aplicação, o que permite usar o cliente em
+/// [modo offline](super::BrasilApiClientBuilder::offline) ou versionar o diretório para
+/// tornar testes determinísticos.
+///
+/// O nome de cada arquivo é derivado da rota. Rotas longas, como domínios grandes do
+/// `registrobr`, têm o nome encurtado com um hash, e a rota original fica gravada no
+/// próprio arquivo para descartar colisões.
+///
+/// # Exemplo
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use brasilapi::client::{BrasilApiClient, Cache, DiskStore};
+///
+/// let store = DiskStore::new("/var/cache/brasilapi").unwrap();
+///
+/// let client = BrasilApiClient::builder()
+///     .cache(Cache::with_store(store).ttl(Duration::from_secs(7 * 24 * 60 * 60)))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DiskStore {
+    dir: PathBuf,
+    writes: AtomicU64,
+}
+
+impl DiskStore {
+    /// Utiliza o diretório `dir`, criando-o se necessário.
+    pub fn new<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            writes: AtomicU64::new(0),
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", file_name(key)))
+    }
+
+    fn entries(&self) -> impl Iterator<Item = PathBuf> {
+        fs::read_dir(&self.dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "json")
+            })
+    }
+}
+
+impl CacheStore for DiskStore {
+    fn get(&self, key: &str) -> Option<CacheEntry> {
+        let content = fs::read(self.entry_path(key)).ok()?;
+        let stored: StoredEntry = serde_json::from_slice(&content).ok()?;
+
+        // Arquivos antigos não têm a rota; neles o nome do arquivo já é a rota inteira.
+        match stored.key {
+            Some(stored_key) if stored_key != key => None,
+            _ => Some(stored.entry),
+        }
+    }
+
+    fn insert(&self, key: &str, entry: CacheEntry) {
+        let stored = StoredEntry {
+            key: Some(key.to_string()),
+            entry,
+        };
+        let Ok(content) = serde_json::to_vec(&stored) else {
+            return;
+        };
+
+        // Escreve em um arquivo temporário e renomeia, para que leituras concorrentes
+        // nunca vejam um arquivo pela metade.
+        let write = self.writes.fetch_add(1, Ordering::Relaxed);
+        let temporary = self.dir.join(format!(
+            ".{}.{}.{write}.tmp",
+            file_name(key),
+            std::process::id()
+        ));
+
+        if fs::write(&temporary, content).is_ok()
+            && fs::rename(&temporary, self.entry_path(key)).is_err()
+        {
+            let _ = fs::remove_file(&temporary);
+        }
+    }
+
+    fn remove(&self, key: &str) {
+        let _ = fs::remove_file(self.entry_path(key));
+    }
+
+    fn clear(&self) {
+        for path in self.entries() {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries().count()
+    }
+}
+
+/// Conteúdo de um arquivo do cache: a resposta e a rota a que ela pertence.
+#[derive(Serialize, Deserialize)]
+struct StoredEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+    #[serde(flatten)]
+    entry: CacheEntry,
+}
+
+/// Nome do arquivo de uma rota: a rota codificada, ou, se ela passar de
+/// [`MAX_FILE_NAME`], o início dela seguido de um hash da rota inteira.
+fn file_name(key: &str) -> String {
+    let encoded = encode_key(key);
+    if encoded.len() <= MAX_FILE_NAME {
+        return encoded;
+    }
+
+    format!(
+        "{}-{:016x}",
+        &encoded[..MAX_FILE_NAME - 17],
+        fnv1a(key.as_bytes())
+    )
+}
+
+/// FNV-1a de 64 bits, estável entre versões do Rust, ao contrário do `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Converte a rota em um nome de arquivo válido, sem colisões entre rotas diferentes.
+fn encode_key(key: &str) -> String {
+    let mut encoded = String::with_capacity(key.len());
+
+    for byte in key.bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("_{byte:02x}")),
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod disk_tests {
+    use super::*;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    fn temporary_dir(name: &str) -> PathBuf {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+
+        std::env::temp_dir().join(format!("brasilapi-{name}-{nanos}"))
+    }
+
+    #[test]
+    fn entries_survive_new_store_instances() {
+        let dir = temporary_dir("disk-store");
+        let entry = CacheEntry {
+            status: 200,
+            body: r#"{"cep":"01001000"}"#.to_string(),
+            expires_at: SystemTime::now() + Duration::from_secs(60),
+        };
+
+        DiskStore::new(&dir)
+            .unwrap()
+            .insert("/api/cep/v2/01001000", entry.clone());
+
+        let store = DiskStore::new(&dir).unwrap();
+        assert_eq!(store.get("/api/cep/v2/01001000"), Some(entry));
+        assert_eq!(store.get("/api/cep/v2/01001001"), None);
+        assert_eq!(store.len(), 1);
+
+        store.clear();
+        assert!(store.is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn keys_are_encoded_without_collisions() {
+        assert_eq!(encode_key("/api/banks/v1"), "_2fapi_2fbanks_2fv1");
+        assert_ne!(encode_key("/a_b"), encode_key("/a/b"));
+        assert_eq!(
+            encode_key("/api/fipe/marcas/v1/carros?"),
+            "_2fapi_2ffipe_2fmarcas_2fv1_2fcarros_3f"
+        );
+    }
+
+    #[test]
+    fn long_keys_fit_in_a_file_name() {
+        let dir = temporary_dir("disk-store-long");
+        let store = DiskStore::new(&dir).unwrap();
+        let domain = format!("{}.com.br", "a".repeat(240));
+        let key = format!("/api/registrobr/v1/{domain}");
+        let other = format!("/api/registrobr/v1/b{}", &domain[1..]);
+        let entry = CacheEntry {
+            status: 200,
+            body: r#"{"status_code":0}"#.to_string(),
+            expires_at: SystemTime::now() + Duration::from_secs(60),
+        };
+
+        store.insert(&key, entry.clone());
+
+        let path = store.entries().next().unwrap();
+        assert!(path.file_name().unwrap().len() <= 255);
+        assert_eq!(store.get(&key), Some(entry));
+
+        // Um arquivo gravado para outra rota, como em uma colisão de hash, é ignorado.
+        fs::rename(&path, store.entry_path(&other)).unwrap();
+        assert_eq!(store.get(&other), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,270 @@
+use std::{fmt, sync::Arc, time::Duration};
+
+use web_time::Instant;
+
+use super::{HttpRequest, HttpResponse, Transport};
+use crate::error::Error;
+
+/// Informações da requisição repassadas aos hooks de um [`Middleware`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestContext {
+    /// Operação que originou a requisição (`cep.get`, `cnpj.get`, `fipe.get_vehicles`, ...).
+    pub operation: &'static str,
+    /// Tentativa atual, começando em 1. Cada retry passa novamente pelos middlewares.
+    pub attempt: u32,
+    /// Tempo desde o início da tentativa; zero em [`Middleware::before`].
+    pub elapsed: Duration,
+}
+
+/// Intercepta as requisições enviadas pelo [`BrasilApiClient`](super::BrasilApiClient).
+///
+/// Os middlewares são executados na ordem em que foram adicionados ao builder antes do
+/// envio, e na ordem inversa depois dele. Todos os hooks têm implementação padrão que
+/// não faz nada.
+///
+/// # Exemplo
+/// ```rust
+/// use brasilapi::client::{BrasilApiClient, HttpRequest, HttpResponse, Middleware, RequestContext};
+///
+/// struct Mirror;
+///
+/// impl Middleware for Mirror {
+///     fn before(&self, request: &mut HttpRequest, _: &RequestContext) -> Option<HttpResponse> {
+///         request.headers.push(("x-correlation-id".to_string(), "abc".to_string()));
+///
+///         if request.path().starts_with("/api/cnpj/") {
+///             request.url = request.url.replace("https://brasilapi.com.br", "https://brasilapi.interno");
+///         }
+///
+///         None
+///     }
+/// }
+///
+/// let client = BrasilApiClient::builder().middleware(Mirror).build().unwrap();
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Chamado antes do envio. Pode alterar a requisição (headers, URL) ou retornar uma
+    /// resposta, que é usada no lugar do envio; nesse caso os middlewares seguintes e o
+    /// [`Transport`] não são chamados.
+    fn before(&self, request: &mut HttpRequest, context: &RequestContext) -> Option<HttpResponse> {
+        let _ = (request, context);
+        None
+    }
+
+    /// Chamado com a resposta, antes de ela ser convertida em resultado. Pode alterá-la.
+    fn after(&self, request: &HttpRequest, response: &mut HttpResponse, context: &RequestContext) {
+        let _ = (request, response, context);
+    }
+
+    /// Chamado quando o [`Transport`] falha (conexão, timeout, TLS).
+    fn on_error(&self, request: &HttpRequest, error: &Error, context: &RequestContext) {
+        let _ = (request, error, context);
+    }
+}
+
+impl<M: Middleware + ?Sized> Middleware for Arc<M> {
+    fn before(&self, request: &mut HttpRequest, context: &RequestContext) -> Option<HttpResponse> {
+        (**self).before(request, context)
+    }
+
+    fn after(&self, request: &HttpRequest, response: &mut HttpResponse, context: &RequestContext) {
+        (**self).after(request, response, context)
+    }
+
+    fn on_error(&self, request: &HttpRequest, error: &Error, context: &RequestContext) {
+        (**self).on_error(request, error, context)
+    }
+}
+
+/// Middlewares de um cliente, em ordem.
+#[derive(Clone, Default)]
+pub(crate) struct MiddlewareChain {
+    middleware: Vec<Arc<dyn Middleware>>,
+}
+
+impl fmt::Debug for MiddlewareChain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MiddlewareChain")
+            .field("len", &self.middleware.len())
+            .finish()
+    }
+}
+
+impl MiddlewareChain {
+    pub(crate) fn push(&mut self, middleware: impl Middleware + 'static) {
+        self.middleware.push(Arc::new(middleware));
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.middleware.is_empty()
+    }
+
+    /// Envia `request` por `transport`, passando pelos hooks dos middlewares.
+    pub(crate) async fn send<T: Transport>(
+        &self,
+        transport: &T,
+        mut request: HttpRequest,
+        operation: &'static str,
+        attempt: u32,
+    ) -> Result<HttpResponse, Error> {
+        let started = Instant::now();
+        let mut context = RequestContext {
+            operation,
+            attempt,
+            elapsed: Duration::ZERO,
+        };
+
+        let mut called = 0;
+        let mut short_circuit = None;
+        for middleware in &self.middleware {
+            called += 1;
+            short_circuit = middleware.before(&mut request, &context);
+            if short_circuit.is_some() {
+                break;
+            }
+        }
+
+        let result = match short_circuit {
+            Some(response) => Ok(response),
+            None if self.is_empty() => return transport.send(request).await,
+            None => transport.send(request.clone()).await,
+        };
+
+        context.elapsed = started.elapsed();
+        let called = &self.middleware[..called];
+
+        match result {
+            Ok(mut response) => {
+                for middleware in called.iter().rev() {
+                    middleware.after(&request, &mut response, &context);
+                }
+                Ok(response)
+            }
+            Err(error) => {
+                for middleware in called.iter().rev() {
+                    middleware.on_error(&request, &error, &context);
+                }
+                Err(error)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod middleware_tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::{
+        client::{BrasilApiClient, InMemoryTransport, RetryPolicy},
+        error::Errored,
+    };
+
+    struct Mirror;
+
+    impl Middleware for Mirror {
+        fn before(&self, request: &mut HttpRequest, _: &RequestContext) -> Option<HttpResponse> {
+            request
+                .headers
+                .push(("x-correlation-id".to_string(), "abc".to_string()));
+            request.url = request
+                .url
+                .replace("https://brasilapi.com.br", "http://mirror.test");
+            None
+        }
+    }
+
+    struct Store;
+
+    impl Middleware for Store {
+        fn before(&self, request: &mut HttpRequest, _: &RequestContext) -> Option<HttpResponse> {
+            (request.path() == "/api/ddd/v1/11")
+                .then(|| HttpResponse::new(200, r#"{"state": "SP", "cities": []}"#))
+        }
+    }
+
+    #[derive(Default)]
+    struct Metrics {
+        calls: Mutex<Vec<(&'static str, u32, u16)>>,
+    }
+
+    impl Middleware for Metrics {
+        fn after(&self, _: &HttpRequest, response: &mut HttpResponse, context: &RequestContext) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push((context.operation, context.attempt, response.status));
+
+            // Trata o 503 do espelho como 404.
+            if response.status == 503 {
+                response.status = 404;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn middleware_rewrites_requests_and_short_circuits() {
+        let transport = InMemoryTransport::new()
+            .route(
+                "/api/taxas/v1/SELIC",
+                200,
+                r#"{"nome": "Selic", "valor": 13.75}"#,
+            )
+            .route("/api/ddd/v1/99", 503, "Service Unavailable");
+        let metrics = Arc::new(Metrics::default());
+        let client = BrasilApiClient::builder()
+            .retry(RetryPolicy::new().backoff(Duration::from_millis(1), Duration::from_millis(1)))
+            .middleware(metrics.clone())
+            .middleware(Store)
+            .middleware(Mirror)
+            .build_with_transport(transport.clone());
+
+        client
+            .get("taxas.get", "/api/taxas/v1/SELIC")
+            .await
+            .unwrap();
+        client.get("ddd.get", "/api/ddd/v1/11").await.unwrap();
+        let error = client.get("ddd.get", "/api/ddd/v1/99").await.unwrap_err();
+
+        // O 404 devolvido pelo middleware não é repetido pelo retry.
+        assert_eq!(error.error, Errored::NotFound);
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].url, "http://mirror.test/api/taxas/v1/SELIC");
+        assert_eq!(
+            requests[0].headers,
+            vec![("x-correlation-id".to_string(), "abc".to_string())]
+        );
+        assert_eq!(requests[1].path(), "/api/ddd/v1/99");
+
+        assert_eq!(
+            *metrics.calls.lock().unwrap(),
+            vec![
+                ("taxas.get", 1, 200),
+                ("ddd.get", 1, 200),
+                ("ddd.get", 1, 503),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn middleware_runs_for_concurrent_identical_requests() {
+        let transport = InMemoryTransport::new()
+            .route("/api/ddd/v1/61", 200, r#"{"state": "DF", "cities": []}"#)
+            .delay("/api/ddd/v1/61", Duration::from_millis(20));
+        let metrics = Arc::new(Metrics::default());
+        let client = BrasilApiClient::builder()
+            .middleware(metrics.clone())
+            .build_with_transport(transport.clone());
+
+        let (a, b) = tokio::join!(
+            client.get("ddd.get", "/api/ddd/v1/61"),
+            client.get("ddd.get", "/api/ddd/v1/61"),
+        );
+
+        assert!(a.is_ok() && b.is_ok());
+        assert_eq!(transport.requests().len(), 2);
+        assert_eq!(metrics.calls.lock().unwrap().len(), 2);
+    }
+}
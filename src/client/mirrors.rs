@@ -0,0 +1,225 @@
+use std::{sync::Mutex, time::Duration};
+
+use web_time::Instant;
+
+use crate::error::{Error, Errored};
+
+/// URLs base do cliente, em ordem de preferência, com o estado de saúde de cada uma.
+///
+/// Uma URL que falha com erro de conexão, timeout ou 5xx fica marcada como indisponível
+/// durante o `cooldown`, e as requisições seguintes vão direto para as próximas URLs.
+/// URLs indisponíveis ainda são tentadas por último, para que uma falha em todas elas
+/// não impeça a recuperação.
+#[derive(Debug)]
+pub(crate) struct Mirrors {
+    mirrors: Vec<Mirror>,
+    cooldown: Duration,
+}
+
+#[derive(Debug)]
+struct Mirror {
+    base_url: String,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl Mirrors {
+    /// Tempo padrão que uma URL fica fora da rotação depois de falhar.
+    pub(crate) const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+    pub(crate) fn new(base_urls: Vec<String>, cooldown: Duration) -> Self {
+        assert!(!base_urls.is_empty(), "at least one base URL is required");
+
+        Self {
+            mirrors: base_urls
+                .into_iter()
+                .map(|base_url| Mirror {
+                    base_url,
+                    unhealthy_until: Mutex::new(None),
+                })
+                .collect(),
+            cooldown,
+        }
+    }
+
+    /// Primeira URL da lista.
+    pub(crate) fn primary(&self) -> &str {
+        &self.mirrors[0].base_url
+    }
+
+    pub(crate) fn base_urls(&self) -> Vec<&str> {
+        self.mirrors
+            .iter()
+            .map(|mirror| mirror.base_url.as_str())
+            .collect()
+    }
+
+    /// URLs na ordem em que devem ser tentadas: primeiro as disponíveis, depois as que
+    /// estão em cooldown, cada grupo na ordem original.
+    pub(crate) fn candidates(&self, now: Instant) -> Vec<&str> {
+        let (healthy, unhealthy): (Vec<_>, Vec<_>) = self
+            .mirrors
+            .iter()
+            .partition(|mirror| mirror.is_healthy(now));
+
+        healthy
+            .into_iter()
+            .chain(unhealthy)
+            .map(|mirror| mirror.base_url.as_str())
+            .collect()
+    }
+
+    /// Atualiza o estado de `base_url` de acordo com o resultado da requisição.
+    pub(crate) fn report<T>(&self, base_url: &str, result: &Result<T, Error>, now: Instant) {
+        let Some(mirror) = self.mirrors.iter().find(|m| m.base_url == base_url) else {
+            return;
+        };
+
+        let mut unhealthy_until = mirror
+            .unhealthy_until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+
+        *unhealthy_until = match result {
+            Err(error) if is_server_failure(error) => Some(now + self.cooldown),
+            _ => None,
+        };
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.mirrors.len()
+    }
+}
+
+impl Mirror {
+    fn is_healthy(&self, now: Instant) -> bool {
+        match *self
+            .unhealthy_until
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+        {
+            Some(until) => until <= now,
+            None => true,
+        }
+    }
+}
+
+/// Indica se o erro aponta uma falha do servidor (erros de conexão, timeouts e respostas
+/// 5xx), que causa o failover para a próxima URL e conta para o circuit breaker.
+pub(crate) fn is_server_failure(error: &Error) -> bool {
+    matches!(error.error, Errored::Connection | Errored::Timeout)
+        || error.code.is_some_and(|code| code >= 500)
+}
+
+#[cfg(test)]
+mod mirrors_tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use crate::client::{BrasilApiClient, HttpRequest, HttpResponse, Transport};
+
+    /// Transporte que responde de acordo com o host da URL.
+    #[derive(Debug, Clone, Default)]
+    struct Hosts {
+        urls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Transport for Hosts {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+            self.urls.lock().unwrap().push(request.url.clone());
+
+            if request.url.starts_with("http://down.test") {
+                Err(Error::new("refused".to_string(), Errored::Connection, None))
+            } else if request.url.starts_with("http://broken.test") {
+                Ok(HttpResponse::new(503, "Service Unavailable"))
+            } else if request.url.ends_with("/api/ddd/v1/00") {
+                Ok(HttpResponse::new(
+                    404,
+                    r#"{"message": "DDD não encontrado"}"#,
+                ))
+            } else {
+                Ok(HttpResponse::new(200, r#"{"state": "DF", "cities": []}"#))
+            }
+        }
+    }
+
+    impl Hosts {
+        fn take(&self) -> Vec<String> {
+            std::mem::take(&mut *self.urls.lock().unwrap())
+        }
+    }
+
+    #[test]
+    fn unhealthy_mirrors_go_last_until_cooldown_ends() {
+        let mirrors = Mirrors::new(
+            vec!["http://a.test".to_string(), "http://b.test".to_string()],
+            Duration::from_secs(30),
+        );
+        let now = Instant::now();
+        let error = Error::new("refused".to_string(), Errored::Connection, None);
+
+        mirrors.report::<()>("http://a.test", &Err(error), now);
+        assert_eq!(mirrors.candidates(now), ["http://b.test", "http://a.test"]);
+        assert_eq!(
+            mirrors.candidates(now + Duration::from_secs(30)),
+            ["http://a.test", "http://b.test"]
+        );
+
+        mirrors.report("http://a.test", &Ok(()), now);
+        assert_eq!(mirrors.candidates(now), ["http://a.test", "http://b.test"]);
+    }
+
+    #[test]
+    fn only_connection_errors_and_5xx_are_server_failures() {
+        assert!(is_server_failure(&Error::from_status(502, String::new())));
+        assert!(is_server_failure(&Error::new(
+            "timed out".to_string(),
+            Errored::Timeout,
+            None
+        )));
+        assert!(!is_server_failure(&Error::from_status(404, String::new())));
+        assert!(!is_server_failure(&Error::from_status(429, String::new())));
+    }
+
+    #[tokio::test]
+    async fn client_fails_over_to_next_base_url() {
+        let transport = Hosts::default();
+        let client = BrasilApiClient::builder()
+            .base_urls(&["http://down.test", "http://broken.test/", "http://ok.test"])
+            .build_with_transport(transport.clone());
+
+        assert_eq!(client.base_url(), "http://down.test");
+        assert_eq!(
+            client.base_urls(),
+            ["http://down.test", "http://broken.test", "http://ok.test"]
+        );
+
+        client.get("ddd.get", "/api/ddd/v1/61").await.unwrap();
+        assert_eq!(
+            transport.take(),
+            [
+                "http://down.test/api/ddd/v1/61",
+                "http://broken.test/api/ddd/v1/61",
+                "http://ok.test/api/ddd/v1/61",
+            ]
+        );
+
+        // As URLs que falharam ficam em cooldown, e um 404 não causa failover.
+        let error = client.get("ddd.get", "/api/ddd/v1/00").await.unwrap_err();
+        assert_eq!(error.error, Errored::NotFound);
+        assert_eq!(transport.take(), ["http://ok.test/api/ddd/v1/00"]);
+    }
+
+    #[tokio::test]
+    async fn client_returns_last_error_when_every_base_url_fails() {
+        let transport = Hosts::default();
+        let client = BrasilApiClient::builder()
+            .base_urls(&["http://down.test", "http://broken.test"])
+            .mirror_cooldown(Duration::ZERO)
+            .build_with_transport(transport.clone());
+
+        let error = client.get("ddd.get", "/api/ddd/v1/61").await.unwrap_err();
+
+        assert_eq!(error.error, Errored::ServiceUnavailable);
+        assert_eq!(transport.take().len(), 2);
+    }
+}
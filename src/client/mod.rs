@@ -0,0 +1,1124 @@
+use std::{
+    future::Future,
+    pin::{pin, Pin},
+    sync::{Arc, OnceLock},
+    task::Poll,
+    time::Duration,
+};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use serde::de::DeserializeOwned;
+use web_time::Instant;
+
+use crate::{
+    error::{Error, Errored},
+    spec::BRASIL_API_URL,
+};
+
+#[cfg(any(feature = "cep", feature = "cnpj", feature = "ddd", feature = "isbn"))]
+pub(crate) mod batch;
+mod cache;
+mod circuit;
+#[cfg(all(feature = "disk-cache", not(target_arch = "wasm32")))]
+mod disk;
+mod middleware;
+mod mirrors;
+mod rate_limit;
+mod retry;
+mod single_flight;
+mod trace;
+mod transport;
+
+pub use cache::{Cache, CacheEntry, CacheStats, CacheStore, MemoryStore};
+pub use circuit::{CircuitBreaker, CircuitState};
+#[cfg(all(feature = "disk-cache", not(target_arch = "wasm32")))]
+pub use disk::DiskStore;
+use middleware::MiddlewareChain;
+pub use middleware::{Middleware, RequestContext};
+use mirrors::Mirrors;
+pub use rate_limit::{Quota, RateLimiter};
+pub use retry::RetryPolicy;
+use single_flight::SingleFlight;
+use trace::RequestSpan;
+pub use transport::{
+    HttpRequest, HttpResponse, InMemoryTransport, MaybeSend, MaybeSync, ReqwestTransport, Transport,
+};
+
+const DEFAULT_USER_AGENT: &str = concat!("brasilapi-rs/", env!("CARGO_PKG_VERSION"));
+
+static DEFAULT_CLIENT: OnceLock<BrasilApiClient> = OnceLock::new();
+
+/// Cliente compartilhado para a BrasilAPI.
+///
+/// O cliente mantém um único [`Transport`] (por padrão o [`ReqwestTransport`], e portanto
+/// um único pool de conexões) junto com a URL base, e expõe as operações de todos os
+/// módulos como métodos. Clonar o cliente é barato e reaproveita as mesmas conexões.
+///
+/// As funções livres de cada módulo (`cep::get_cep`, `bank::get_all_banks`, ...) utilizam
+/// um cliente padrão criado na primeira chamada.
+///
+/// # Exemplo
+/// ```rust
+/// use std::time::Duration;
+/// use brasilapi::client::BrasilApiClient;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = BrasilApiClient::builder()
+///         .timeout(Duration::from_secs(10))
+///         .user_agent("minha-aplicacao/1.0")
+///         .build()
+///         .unwrap();
+///
+///     let cep = client.get_cep("01001000").await.unwrap();
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BrasilApiClient<T = ReqwestTransport> {
+    transport: Arc<T>,
+    mirrors: Arc<Mirrors>,
+    retry: RetryPolicy,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    cache: Option<Arc<Cache>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    middleware: MiddlewareChain,
+    single_flight: Option<Arc<SingleFlight>>,
+    deadline: Option<Duration>,
+    offline: bool,
+}
+
+impl<T> Clone for BrasilApiClient<T> {
+    fn clone(&self) -> Self {
+        Self {
+            transport: self.transport.clone(),
+            mirrors: self.mirrors.clone(),
+            retry: self.retry.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            cache: self.cache.clone(),
+            circuit_breaker: self.circuit_breaker.clone(),
+            middleware: self.middleware.clone(),
+            single_flight: self.single_flight.clone(),
+            deadline: self.deadline,
+            offline: self.offline,
+        }
+    }
+}
+
+/// Resposta bem-sucedida já lida da API.
+#[derive(Debug, Clone)]
+pub(crate) struct Response {
+    pub(crate) status: u16,
+    pub(crate) body: String,
+}
+
+impl Response {
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn json<T: DeserializeOwned>(self) -> Result<T, Error> {
+        Error::decode_body(self.body, Some(self.status))
+    }
+}
+
+impl Default for BrasilApiClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BrasilApiClient {
+    /// Cria um cliente com a configuração padrão apontando para a BrasilAPI.
+    pub fn new() -> Self {
+        Self::with_base_url(BRASIL_API_URL)
+    }
+
+    /// Cria um cliente com a configuração padrão apontando para `base_url`.
+    ///
+    /// Equivale a `BrasilApiClient::builder().base_url(base_url).build()`, incluindo o
+    /// `User-Agent` padrão.
+    pub fn with_base_url(base_url: &str) -> Self {
+        Self::builder()
+            .base_url(base_url)
+            .build()
+            .expect("the default client configuration is always valid")
+    }
+
+    /// Retorna um [`BrasilApiClientBuilder`] para configurar o cliente.
+    pub fn builder() -> BrasilApiClientBuilder {
+        BrasilApiClientBuilder::new()
+    }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Cria um cliente com a configuração padrão que envia as requisições por `transport`.
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            mirrors: Arc::new(Mirrors::new(
+                vec![BRASIL_API_URL.to_string()],
+                Mirrors::DEFAULT_COOLDOWN,
+            )),
+            retry: RetryPolicy::none(),
+            rate_limiter: None,
+            cache: None,
+            circuit_breaker: None,
+            middleware: MiddlewareChain::default(),
+            single_flight: Some(Arc::default()),
+            deadline: None,
+            offline: false,
+        }
+    }
+
+    /// URL base utilizada nas requisições; com várias URLs, a primeira delas.
+    pub fn base_url(&self) -> &str {
+        self.mirrors.primary()
+    }
+
+    /// URLs base do cliente, em ordem de preferência.
+    pub fn base_urls(&self) -> Vec<&str> {
+        self.mirrors.base_urls()
+    }
+
+    /// Transporte utilizado pelo cliente.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Estatísticas do cache, se o cliente tiver um.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Remove todas as respostas do cache, se o cliente tiver um.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+
+    /// Estado do circuito de uma família de endpoints (`cep`, `cnpj`, ...), se o cliente
+    /// tiver um [`CircuitBreaker`].
+    pub fn circuit_state(&self, family: &str) -> Option<CircuitState> {
+        self.circuit_breaker
+            .as_ref()
+            .map(|breaker| breaker.state(family))
+    }
+
+    /// Retorna uma cópia do cliente em que cada chamada tem no máximo `deadline` para
+    /// terminar, substituindo o [`deadline`](BrasilApiClientBuilder::deadline) do builder.
+    ///
+    /// ```rust,no_run
+    /// # use std::time::Duration;
+    /// # use brasilapi::client::BrasilApiClient;
+    /// # async fn run(client: BrasilApiClient) {
+    /// let cep = client.deadline(Duration::from_secs(2)).get_cep("01001000").await;
+    /// # }
+    /// ```
+    pub fn deadline(&self, deadline: Duration) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..self.clone()
+        }
+    }
+
+    /// Indica se o cliente está em modo offline.
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Faz a requisição `GET path`; `operation` identifica a chamada nos spans do `tracing`.
+    #[allow(clippy::result_large_err)]
+    pub(crate) async fn get(&self, operation: &'static str, path: &str) -> Result<Response, Error> {
+        let span = RequestSpan::new(operation, self.mirrors.primary(), path);
+        let call = span.instrument(self.get_cached(operation, &span, path));
+
+        let result = match self.deadline {
+            Some(deadline) => timeout(deadline, call).await.unwrap_or_else(|| {
+                Err(Error::new(
+                    format!("{operation} did not finish within {deadline:?}"),
+                    Errored::Timeout,
+                    None,
+                ))
+            }),
+            None => call.await,
+        };
+        span.finish(&result);
+
+        result
+    }
+
+    #[allow(clippy::result_large_err)]
+    async fn get_cached(
+        &self,
+        operation: &'static str,
+        span: &RequestSpan,
+        path: &str,
+    ) -> Result<Response, Error> {
+        if self.offline {
+            span.cache("offline");
+
+            return self
+                .cache
+                .as_ref()
+                .and_then(|cache| cache.lookup_stale(path))
+                .unwrap_or_else(|| {
+                    Err(Error::new(
+                        format!("{path} is not available offline"),
+                        Errored::Offline,
+                        None,
+                    ))
+                });
+        }
+
+        if let Some(cache) = &self.cache {
+            if let Some(cached) = cache.lookup(path) {
+                span.cache("hit");
+                return cached;
+            }
+
+            span.cache("miss");
+        }
+
+        match &self.single_flight {
+            Some(single_flight) => {
+                single_flight
+                    .run(path, || self.fetch_and_cache(operation, span, path))
+                    .await
+            }
+            None => self.fetch_and_cache(operation, span, path).await,
+        }
+    }
+
+    async fn fetch_and_cache(
+        &self,
+        operation: &'static str,
+        span: &RequestSpan,
+        path: &str,
+    ) -> Result<Response, Error> {
+        let result = self.fetch(operation, span, path).await;
+
+        if let Some(cache) = &self.cache {
+            cache.update(path, &result);
+        }
+
+        result
+    }
+
+    async fn fetch(
+        &self,
+        operation: &'static str,
+        span: &RequestSpan,
+        path: &str,
+    ) -> Result<Response, Error> {
+        let mut attempt = 1;
+
+        loop {
+            span.attempt(attempt);
+
+            if let Some(breaker) = &self.circuit_breaker {
+                breaker.acquire(circuit::family(operation), Instant::now())?;
+            }
+
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire(path).await;
+            }
+
+            let (result, retry_after) = self.send_to_mirrors(operation, span, path, attempt).await;
+
+            if let Some(breaker) = &self.circuit_breaker {
+                breaker.report(circuit::family(operation), &result, Instant::now());
+            }
+
+            let error = match result {
+                Ok(response) => return Ok(response),
+                Err(error) => error,
+            };
+
+            match self.retry.next_delay(&error, attempt, retry_after) {
+                Some(delay) => {
+                    span.retry(&error, attempt, delay);
+                    sleep(delay).await
+                }
+                None => return Err(error),
+            }
+
+            attempt += 1;
+        }
+    }
+
+    /// Envia uma tentativa para a primeira URL base disponível, passando para a próxima
+    /// em erros de conexão ou 5xx.
+    async fn send_to_mirrors(
+        &self,
+        operation: &'static str,
+        span: &RequestSpan,
+        path: &str,
+        attempt: u32,
+    ) -> (Result<Response, Error>, Option<Duration>) {
+        let candidates = self.mirrors.candidates(Instant::now());
+        let last = candidates.len() - 1;
+
+        for (index, base_url) in candidates.into_iter().enumerate() {
+            span.endpoint(base_url);
+
+            let request = HttpRequest::get(&format!("{base_url}{path}"));
+            let (result, retry_after) = match self
+                .middleware
+                .send(&*self.transport, request, operation, attempt)
+                .await
+            {
+                Ok(response) => {
+                    let retry_after = retry::parse_retry_after(response.header("retry-after"));
+                    (read_response(response), retry_after)
+                }
+                Err(error) => (Err(error), None),
+            };
+
+            if self.mirrors.len() > 1 {
+                self.mirrors.report(base_url, &result, Instant::now());
+            }
+
+            match &result {
+                Err(error) if index < last && mirrors::is_server_failure(error) => continue,
+                _ => return (result, retry_after),
+            }
+        }
+
+        unreachable!("there is always at least one base URL")
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn read_response(response: HttpResponse) -> Result<Response, Error> {
+    let status = response.status;
+
+    let body = match String::from_utf8(response.body) {
+        Ok(body) => body,
+        Err(e) => return Err(Error::new(e.to_string(), Errored::Body, Some(status)).with_source(e)),
+    };
+
+    match status {
+        200 => Ok(Response { status, body }),
+        _ => Err(Error::from_status(status, body)),
+    }
+}
+
+/// Espera sem depender de um runtime específico, para que o retry, o rate limit e o
+/// [`InMemoryTransport`] funcionem em qualquer executor.
+pub(crate) async fn sleep(duration: Duration) {
+    futures_timer::Delay::new(duration).await
+}
+
+/// Executa `future` até o fim, ou retorna `None` se `duration` passar antes.
+pub(crate) async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    let mut future = pin!(future);
+    let mut delay = futures_timer::Delay::new(duration);
+
+    std::future::poll_fn(|cx| {
+        if let Poll::Ready(output) = future.as_mut().poll(cx) {
+            return Poll::Ready(Some(output));
+        }
+
+        Pin::new(&mut delay).poll(cx).map(|()| None)
+    })
+    .await
+}
+
+/// Cliente utilizado pelas funções livres dos módulos.
+pub(crate) fn default_client() -> &'static BrasilApiClient {
+    DEFAULT_CLIENT.get_or_init(BrasilApiClient::new)
+}
+
+/// Builder para o [`BrasilApiClient`].
+#[derive(Debug)]
+pub struct BrasilApiClientBuilder {
+    base_urls: Vec<String>,
+    mirror_cooldown: Duration,
+    user_agent: String,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxies: Vec<reqwest::Proxy>,
+    default_headers: HeaderMap,
+    http: Option<reqwest::Client>,
+    retry: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    cache: Option<Cache>,
+    circuit_breaker: Option<CircuitBreaker>,
+    middleware: MiddlewareChain,
+    coalesce_requests: Option<bool>,
+    deadline: Option<Duration>,
+    offline: bool,
+}
+
+impl Default for BrasilApiClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BrasilApiClientBuilder {
+    pub fn new() -> Self {
+        Self {
+            base_urls: vec![BRASIL_API_URL.to_string()],
+            mirror_cooldown: Mirrors::DEFAULT_COOLDOWN,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            connect_timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxies: Vec::new(),
+            default_headers: HeaderMap::new(),
+            http: None,
+            retry: RetryPolicy::none(),
+            rate_limiter: None,
+            cache: None,
+            circuit_breaker: None,
+            middleware: MiddlewareChain::default(),
+            coalesce_requests: None,
+            deadline: None,
+            offline: false,
+        }
+    }
+
+    /// Define a URL base, útil para instâncias próprias da BrasilAPI.
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_urls = vec![base_url.trim_end_matches('/').to_string()];
+        self
+    }
+
+    /// Define várias URLs base, em ordem de preferência, como espelhos da BrasilAPI.
+    ///
+    /// Em erros de conexão, timeouts ou respostas 5xx a requisição é enviada para a
+    /// próxima URL, e a que falhou fica fora da rotação durante o
+    /// [`mirror_cooldown`](Self::mirror_cooldown). Uma lista vazia é ignorada.
+    pub fn base_urls(mut self, base_urls: &[&str]) -> Self {
+        if !base_urls.is_empty() {
+            self.base_urls = base_urls
+                .iter()
+                .map(|base_url| base_url.trim_end_matches('/').to_string())
+                .collect();
+        }
+        self
+    }
+
+    /// Tempo que uma URL base fica fora da rotação depois de falhar. O padrão é 30s.
+    pub fn mirror_cooldown(mut self, cooldown: Duration) -> Self {
+        self.mirror_cooldown = cooldown;
+        self
+    }
+
+    /// Define o `User-Agent` enviado em todas as requisições.
+    ///
+    /// No wasm32 o `User-Agent` padrão não é enviado, pois no navegador o header
+    /// dispara um preflight de CORS.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_string();
+        self
+    }
+
+    /// Tempo máximo de cada requisição, do envio até a leitura da resposta.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Tempo máximo para estabelecer a conexão.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Adiciona um proxy às requisições.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Adiciona um header enviado em todas as requisições.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Utiliza um `reqwest::Client` já configurado.
+    ///
+    /// Quando definido, as opções de `user_agent`, `timeout`, `connect_timeout`,
+    /// `proxy` e `default_header` são ignoradas.
+    pub fn http_client(mut self, http: reqwest::Client) -> Self {
+        self.http = Some(http);
+        self
+    }
+
+    /// Tempo máximo de cada chamada, incluindo novas tentativas, esperas do rate limit e
+    /// failover entre URLs. Ao estourar, a chamada retorna [`Errored::Timeout`].
+    ///
+    /// Diferente do [`timeout`](Self::timeout), que limita cada requisição HTTP, o
+    /// deadline funciona também no wasm32. Pode ser alterado por chamada com
+    /// [`BrasilApiClient::deadline`].
+    pub fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Define a [`RetryPolicy`] aplicada a todas as requisições.
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Define o [`RateLimiter`] compartilhado por todas as requisições do cliente.
+    pub fn rate_limit(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Define o [`Cache`] de respostas do cliente.
+    pub fn cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Define o [`CircuitBreaker`] compartilhado por todas as requisições do cliente.
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Adiciona um [`Middleware`] ao fim da cadeia de middlewares do cliente.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Agrupa chamadas idênticas feitas ao mesmo tempo (o mesmo endpoint com os mesmos
+    /// parâmetros) em uma única requisição, cujo resultado é entregue a todas elas.
+    ///
+    /// As chamadas agrupadas não passam pelos [`Middleware`]s nem pelo retry: recebem o
+    /// resultado da primeira chamada. Por isso o padrão é habilitado apenas quando o
+    /// cliente não tem middlewares.
+    pub fn coalesce_requests(mut self, coalesce: bool) -> Self {
+        self.coalesce_requests = Some(coalesce);
+        self
+    }
+
+    /// Modo offline: as respostas vêm apenas do [`Cache`], inclusive as expiradas, e
+    /// nenhuma requisição é feita. Consultas que não estão no cache retornam
+    /// [`Errored::Offline`].
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Constrói o [`BrasilApiClient`] com o [`ReqwestTransport`].
+    #[allow(clippy::result_large_err)]
+    pub fn build(mut self) -> Result<BrasilApiClient, Error> {
+        let transport = self.reqwest_transport()?;
+
+        Ok(self.build_with_transport(transport))
+    }
+
+    /// Monta o [`ReqwestTransport`] com as opções do `reqwest`, incluindo o backend TLS
+    /// escolhido pelas features.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn reqwest_transport(&mut self) -> Result<ReqwestTransport, Error> {
+        let http = match self.http.take() {
+            Some(http) => http,
+            None => {
+                let mut builder = reqwest::Client::builder()
+                    .default_headers(std::mem::take(&mut self.default_headers));
+
+                if cfg!(not(target_arch = "wasm32")) || self.user_agent != DEFAULT_USER_AGENT {
+                    builder = builder.user_agent(self.user_agent.as_str());
+                }
+
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    if let Some(timeout) = self.timeout {
+                        builder = builder.timeout(timeout);
+                    }
+
+                    if let Some(timeout) = self.connect_timeout {
+                        builder = builder.connect_timeout(timeout);
+                    }
+
+                    for proxy in std::mem::take(&mut self.proxies) {
+                        builder = builder.proxy(proxy);
+                    }
+
+                    // Com mais de um backend habilitado, o `reqwest` prefere o native-tls.
+                    #[cfg(feature = "rustls-tls")]
+                    {
+                        builder = builder.use_rustls_tls();
+                    }
+                }
+
+                builder.build().map_err(Error::from_error)?
+            }
+        };
+
+        Ok(ReqwestTransport::new(http))
+    }
+
+    /// Constrói o [`BrasilApiClient`] enviando as requisições por `transport`.
+    ///
+    /// As opções específicas do `reqwest` (`user_agent`, `timeout`, `connect_timeout`,
+    /// `proxy`, `default_header` e `http_client`) são ignoradas.
+    pub fn build_with_transport<T: Transport>(self, transport: T) -> BrasilApiClient<T> {
+        BrasilApiClient {
+            transport: Arc::new(transport),
+            mirrors: Arc::new(Mirrors::new(self.base_urls, self.mirror_cooldown)),
+            retry: self.retry,
+            rate_limiter: self.rate_limiter.map(Arc::new),
+            cache: self.cache.map(Arc::new),
+            circuit_breaker: self.circuit_breaker.map(Arc::new),
+            single_flight: self
+                .coalesce_requests
+                .unwrap_or(self.middleware.is_empty())
+                .then(Arc::default),
+            middleware: self.middleware,
+            deadline: self.deadline,
+            offline: self.offline,
+        }
+    }
+}
+
+#[cfg(test)]
+mod client_tests {
+    use super::*;
+
+    #[cfg(feature = "bank")]
+    #[tokio::test]
+    async fn builder_sends_user_agent_and_default_headers() {
+        use httpmock::MockServer;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/api/banks/v1/1")
+                    .header("user-agent", "brasilapi-tests")
+                    .header("x-correlation-id", "abc");
+                then.status(200).json_body(json!({
+                    "ispb": "00000000",
+                    "name": "BCO DO BRASIL S.A.",
+                    "code": 1,
+                    "fullName": "Banco do Brasil S.A."
+                }));
+            })
+            .await;
+
+        let client = BrasilApiClient::builder()
+            .base_url(&server.base_url())
+            .user_agent("brasilapi-tests")
+            .default_header(
+                HeaderName::from_static("x-correlation-id"),
+                HeaderValue::from_static("abc"),
+            )
+            .build()
+            .unwrap();
+
+        let bank = client.get_bank(1).await.unwrap();
+
+        mock.assert_async().await;
+        assert_eq!(bank.code, Some(1));
+    }
+
+    #[test]
+    fn default_clients_match_the_builder_configuration() {
+        let built = BrasilApiClient::builder().build().unwrap();
+        let expected = format!("{:?}", built.transport());
+
+        assert!(expected.contains(DEFAULT_USER_AGENT));
+        assert_eq!(
+            format!("{:?}", BrasilApiClient::new().transport()),
+            expected
+        );
+        assert_eq!(format!("{:?}", default_client().transport()), expected);
+        assert_eq!(format!("{:?}", ReqwestTransport::default()), expected);
+    }
+
+    #[cfg(feature = "bank")]
+    #[tokio::test]
+    async fn default_client_sends_default_user_agent() {
+        use httpmock::MockServer;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET")
+                    .path("/api/banks/v1")
+                    .header("user-agent", DEFAULT_USER_AGENT);
+                then.status(200).body("[]");
+            })
+            .await;
+
+        let client = BrasilApiClient::with_base_url(&server.base_url());
+        let banks = client.get_all_banks().await.unwrap();
+
+        mock.assert_async().await;
+        assert!(banks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_invalid_utf8_bodies() {
+        let transport = InMemoryTransport::new()
+            .respond("/api/ddd/v1/61", HttpResponse::new(200, vec![0xff, 0xfe]));
+        let client = BrasilApiClient::builder()
+            .retry(
+                RetryPolicy::new()
+                    .max_attempts(3)
+                    .backoff(Duration::from_millis(1), Duration::from_millis(5)),
+            )
+            .build_with_transport(transport.clone());
+
+        let error = client.get("ddd.get", "/api/ddd/v1/61").await.unwrap_err();
+
+        assert_eq!(error.error, Errored::Body);
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[cfg(feature = "cnpj")]
+    #[tokio::test]
+    async fn retries_transient_errors_until_max_attempts() {
+        use httpmock::MockServer;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/cnpj/v1/00000000000191");
+                then.status(503).body("Service Unavailable");
+            })
+            .await;
+
+        let client = BrasilApiClient::builder()
+            .base_url(&server.base_url())
+            .retry(
+                RetryPolicy::new()
+                    .max_attempts(3)
+                    .backoff(Duration::from_millis(1), Duration::from_millis(5)),
+            )
+            .build()
+            .unwrap();
+
+        let error = client.get_cnpj("00000000000191").await.unwrap_err();
+
+        assert_eq!(mock.hits_async().await, 3);
+        assert_eq!(error.code, Some(503));
+    }
+
+    #[cfg(feature = "ddd")]
+    #[tokio::test]
+    async fn does_not_retry_not_found_or_long_retry_after() {
+        use httpmock::MockServer;
+
+        let server = MockServer::start_async().await;
+        let not_found = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/ddd/v1/00");
+                then.status(404).body("{}");
+            })
+            .await;
+        let limited = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/ddd/v1/11");
+                then.status(429).header("Retry-After", "3600").body("{}");
+            })
+            .await;
+
+        let client = BrasilApiClient::builder()
+            .base_url(&server.base_url())
+            .retry(RetryPolicy::new().backoff(Duration::from_millis(1), Duration::from_millis(5)))
+            .build()
+            .unwrap();
+
+        assert!(client.get_ddd("00").await.unwrap_err().is_not_found());
+        assert_eq!(
+            client.get_ddd("11").await.unwrap_err().error,
+            crate::error::Errored::TooManyRequests
+        );
+
+        assert_eq!(not_found.hits_async().await, 1);
+        assert_eq!(limited.hits_async().await, 1);
+    }
+
+    #[cfg(feature = "holidays")]
+    #[tokio::test]
+    async fn rate_limiter_delays_requests_over_budget() {
+        use httpmock::MockServer;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/feriados/v1/2022");
+                then.status(200).json_body(json!([]));
+            })
+            .await;
+
+        let client = BrasilApiClient::builder()
+            .base_url(&server.base_url())
+            .rate_limit(RateLimiter::new(Quota::per_second(20).burst(1)))
+            .build()
+            .unwrap();
+
+        let start = std::time::Instant::now();
+        for _ in 0..3 {
+            client.clone().get_holidays("2022").await.unwrap();
+        }
+
+        assert_eq!(mock.hits_async().await, 3);
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[cfg(all(feature = "bank", feature = "cep"))]
+    #[tokio::test]
+    async fn cache_serves_repeated_lookups_and_not_found() {
+        use httpmock::MockServer;
+        use serde_json::json;
+
+        let server = MockServer::start_async().await;
+        let banks = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/banks/v1");
+                then.status(200).json_body(json!([]));
+            })
+            .await;
+        let missing = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/cep/v2/00000000");
+                then.status(404).json_body(json!({
+                    "name": "CepPromiseError",
+                    "message": "Todos os serviços de CEP retornaram erro.",
+                    "type": "service_error",
+                }));
+            })
+            .await;
+
+        let client = BrasilApiClient::builder()
+            .base_url(&server.base_url())
+            .cache(Cache::new(100).negative_ttl(Duration::from_secs(60)))
+            .build()
+            .unwrap();
+
+        for _ in 0..3 {
+            assert!(client.get_all_banks().await.unwrap().is_empty());
+            assert!(!client.validate_cep("00000000").await.unwrap());
+        }
+
+        assert_eq!(banks.hits_async().await, 1);
+        assert_eq!(missing.hits_async().await, 1);
+        assert_eq!(
+            client.cache_stats(),
+            Some(CacheStats {
+                hits: 4,
+                misses: 2,
+                negative_hits: 2,
+                entries: 2,
+            })
+        );
+
+        client.clear_cache();
+        assert_eq!(client.cache_stats().unwrap().entries, 0);
+    }
+
+    #[cfg(all(feature = "holidays", feature = "disk-cache"))]
+    #[tokio::test]
+    async fn offline_mode_serves_disk_store_without_network() {
+        use httpmock::MockServer;
+        use serde_json::json;
+
+        let dir = std::env::temp_dir().join(format!(
+            "brasilapi-offline-{}",
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+
+        let server = MockServer::start_async().await;
+        let mock = server
+            .mock_async(|when, then| {
+                when.method("GET").path("/api/feriados/v1/2022");
+                then.status(200).json_body(json!([{
+                    "date": "2022-09-07",
+                    "type": "national",
+                    "name": "Independência do Brasil",
+                }]));
+            })
+            .await;
+
+        let online = BrasilApiClient::builder()
+            .base_url(&server.base_url())
+            .cache(Cache::with_store(DiskStore::new(&dir).unwrap()).ttl(Duration::ZERO))
+            .build()
+            .unwrap();
+        online.get_holidays("2022").await.unwrap();
+
+        // O TTL zero desabilita o cache, então nada foi gravado.
+        assert_eq!(online.cache_stats().unwrap().entries, 0);
+
+        let online = BrasilApiClient::builder()
+            .base_url(&server.base_url())
+            .cache(Cache::with_store(DiskStore::new(&dir).unwrap()))
+            .build()
+            .unwrap();
+        online.get_holidays("2022").await.unwrap();
+
+        let offline = BrasilApiClient::builder()
+            .base_url("http://127.0.0.1:9")
+            .cache(Cache::with_store(DiskStore::new(&dir).unwrap()))
+            .offline(true)
+            .build()
+            .unwrap();
+
+        let holiday = offline.get_holiday("2022", "09", "07").await.unwrap();
+        let missing = offline.get_holidays("2023").await.unwrap_err();
+
+        assert_eq!(holiday.name, "Independência do Brasil");
+        assert_eq!(missing.error, Errored::Offline);
+        assert_eq!(mock.hits_async().await, 2);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "cnpj")]
+    #[tokio::test]
+    async fn custom_transport_receives_requests_and_honours_retry_after() {
+        let transport = InMemoryTransport::new().respond(
+            "/api/cnpj/v1/00000000000191",
+            HttpResponse::new(429, "Too Many Requests").with_header("Retry-After", "0"),
+        );
+
+        let client = BrasilApiClient::builder()
+            .base_url("http://brasilapi.test")
+            .retry(RetryPolicy::new().max_attempts(2))
+            .build_with_transport(transport.clone());
+
+        let error = client.get_cnpj("00000000000191").await.unwrap_err();
+        let requests = transport.requests();
+
+        assert_eq!(error.error, Errored::TooManyRequests);
+        assert_eq!(requests.len(), 2);
+        assert_eq!(
+            requests[0].url,
+            "http://brasilapi.test/api/cnpj/v1/00000000000191"
+        );
+    }
+
+    #[cfg(feature = "bank")]
+    #[tokio::test]
+    async fn invalid_utf8_body_is_a_body_error() {
+        let transport =
+            InMemoryTransport::new().respond("/api/banks/v1", HttpResponse::new(200, vec![0xff]));
+
+        let error = BrasilApiClient::with_transport(transport)
+            .get_all_banks()
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error, Errored::Body);
+        assert_eq!(error.code, Some(200));
+    }
+
+    #[cfg(feature = "holidays")]
+    #[test]
+    fn core_runs_without_tokio_runtime() {
+        let transport = InMemoryTransport::new()
+            .respond(
+                "/api/feriados/v1/2022",
+                HttpResponse::new(503, "Service Unavailable"),
+            )
+            .delay("/api/feriados/v1/2022", Duration::from_millis(5));
+
+        let client = BrasilApiClient::builder()
+            .retry(
+                RetryPolicy::new()
+                    .max_attempts(2)
+                    .backoff(Duration::from_millis(5), Duration::from_millis(5)),
+            )
+            .rate_limit(RateLimiter::new(Quota::per_second(100).burst(1)))
+            .build_with_transport(transport.clone());
+
+        let error = futures::executor::block_on(client.get_holidays("2022")).unwrap_err();
+
+        assert_eq!(error.error, Errored::ServiceUnavailable);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn deadline_bounds_the_whole_call() {
+        let transport = InMemoryTransport::new()
+            .route("/api/taxas/v1/SELIC", 503, "Service Unavailable")
+            .route(
+                "/api/taxas/v1/CDI",
+                200,
+                r#"{"nome": "CDI", "valor": 13.65}"#,
+            )
+            .delay("/api/taxas/v1/CDI", Duration::from_millis(100));
+        let client = BrasilApiClient::builder()
+            .retry(
+                RetryPolicy::new()
+                    .max_attempts(10)
+                    .backoff(Duration::from_secs(5), Duration::from_secs(5)),
+            )
+            .deadline(Duration::from_millis(20))
+            .build_with_transport(transport.clone());
+
+        let started = std::time::Instant::now();
+        let error = client
+            .get("taxas.get", "/api/taxas/v1/SELIC")
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.error, Errored::Timeout);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(transport.requests().len(), 1);
+
+        let error = client
+            .get("taxas.get", "/api/taxas/v1/CDI")
+            .await
+            .unwrap_err();
+        assert_eq!(error.error, Errored::Timeout);
+
+        let slower = client.deadline(Duration::from_secs(5));
+        assert!(slower.get("taxas.get", "/api/taxas/v1/CDI").await.is_ok());
+    }
+
+    #[test]
+    fn client_futures_are_send_for_any_transport() {
+        fn assert_send<F: Send>(_: &F) {}
+
+        fn check<T: Transport>(client: &BrasilApiClient<T>) {
+            assert_send(&client.get("bank.list", "/api/banks/v1"));
+        }
+
+        check(&BrasilApiClient::with_transport(InMemoryTransport::new()));
+        check(&BrasilApiClient::new());
+    }
+
+    #[test]
+    fn base_url_trailing_slash_is_trimmed() {
+        let client = BrasilApiClient::builder()
+            .base_url("http://localhost:3000/")
+            .build()
+            .unwrap();
+
+        assert_eq!(client.base_url(), "http://localhost:3000");
+        assert_eq!(
+            BrasilApiClient::with_base_url("http://localhost:3000/").base_url(),
+            "http://localhost:3000"
+        );
+    }
+
+    #[test]
+    fn default_client_is_shared() {
+        assert!(std::ptr::eq(default_client(), default_client()));
+        assert_eq!(default_client().base_url(), BRASIL_API_URL);
+    }
+}
@@ -0,0 +1,190 @@
+use std::{sync::Mutex, time::Duration};
+
+use web_time::Instant;
+
+/// Orçamento de requisições de um token bucket.
+///
+/// `rate` tokens são repostos por segundo, e até `burst` requisições podem ser feitas
+/// de uma vez quando o bucket está cheio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quota {
+    rate: f64,
+    burst: u32,
+}
+
+impl Quota {
+    /// `requests` requisições por segundo, com burst igual a `requests`.
+    pub fn per_second(requests: u32) -> Self {
+        Self {
+            rate: f64::from(requests.max(1)),
+            burst: requests.max(1),
+        }
+    }
+
+    /// `requests` requisições por minuto, com burst de uma requisição.
+    pub fn per_minute(requests: u32) -> Self {
+        Self {
+            rate: f64::from(requests.max(1)) / 60.0,
+            burst: 1,
+        }
+    }
+
+    /// Define quantas requisições podem ser feitas de uma vez.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+/// Limitador de requisições do cliente, baseado em token bucket.
+///
+/// Todas as requisições do cliente (e de seus clones) passam pelo mesmo limitador.
+/// Quando o orçamento acaba, a requisição aguarda até que haja um token disponível
+/// em vez de falhar.
+///
+/// Prefixos de rota podem ter um orçamento próprio, que substitui o orçamento geral
+/// para as requisições que começam com o prefixo. Se mais de um prefixo corresponder,
+/// o mais longo é utilizado.
+///
+/// # Exemplo
+/// ```rust
+/// use brasilapi::client::{BrasilApiClient, Quota, RateLimiter};
+///
+/// let client = BrasilApiClient::builder()
+///     .rate_limit(
+///         RateLimiter::new(Quota::per_second(10).burst(20))
+///             .endpoint("/api/cnpj/v1", Quota::per_minute(3)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct RateLimiter {
+    global: Bucket,
+    endpoints: Vec<(String, Bucket)>,
+}
+
+impl RateLimiter {
+    pub fn new(quota: Quota) -> Self {
+        Self {
+            global: Bucket::new(quota),
+            endpoints: Vec::new(),
+        }
+    }
+
+    /// Define um orçamento próprio para as rotas que começam com `prefix`.
+    pub fn endpoint(mut self, prefix: &str, quota: Quota) -> Self {
+        self.endpoints
+            .push((prefix.to_string(), Bucket::new(quota)));
+        self
+    }
+
+    /// Aguarda até que uma requisição para `path` possa ser feita.
+    pub(crate) async fn acquire(&self, path: &str) {
+        let wait = self.bucket(path).reserve(Instant::now());
+
+        if !wait.is_zero() {
+            super::sleep(wait).await;
+        }
+    }
+
+    fn bucket(&self, path: &str) -> &Bucket {
+        self.endpoints
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, bucket)| bucket)
+            .unwrap_or(&self.global)
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    quota: Quota,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            state: Mutex::new(BucketState {
+                tokens: f64::from(quota.burst),
+                updated_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Reserva um token e retorna quanto tempo é preciso esperar por ele.
+    ///
+    /// Os tokens podem ficar negativos: cada requisição em espera reserva o seu lugar
+    /// na fila, então requisições concorrentes não competem pelo mesmo token.
+    fn reserve(&self, now: Instant) -> Duration {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+
+        let elapsed = now
+            .saturating_duration_since(state.updated_at)
+            .as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.quota.rate).min(f64::from(self.quota.burst));
+        state.updated_at = now.max(state.updated_at);
+        state.tokens -= 1.0;
+
+        if state.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-state.tokens / self.quota.rate)
+        }
+    }
+}
+
+#[cfg(test)]
+mod rate_limit_tests {
+    use super::*;
+
+    #[test]
+    fn burst_is_free_then_requests_are_spaced() {
+        let bucket = Bucket::new(Quota::per_second(10).burst(2));
+        let now = Instant::now();
+
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now), Duration::from_millis(100));
+        assert_eq!(bucket.reserve(now), Duration::from_millis(200));
+    }
+
+    #[test]
+    fn tokens_refill_over_time_up_to_burst() {
+        let bucket = Bucket::new(Quota::per_second(10).burst(1));
+        let now = Instant::now();
+
+        assert_eq!(bucket.reserve(now), Duration::ZERO);
+        assert_eq!(bucket.reserve(now + Duration::from_secs(5)), Duration::ZERO);
+        assert_eq!(
+            bucket.reserve(now + Duration::from_secs(5)),
+            Duration::from_millis(100)
+        );
+    }
+
+    #[test]
+    fn longest_matching_prefix_overrides_global_quota() {
+        let limiter = RateLimiter::new(Quota::per_second(100))
+            .endpoint("/api/cnpj", Quota::per_second(5))
+            .endpoint("/api/cnpj/v1", Quota::per_minute(3));
+
+        assert_eq!(
+            limiter.bucket("/api/cnpj/v1/00000000000191").quota,
+            Quota::per_minute(3)
+        );
+        assert_eq!(limiter.bucket("/api/cnpj/v2/1").quota, Quota::per_second(5));
+        assert_eq!(
+            limiter.bucket("/api/cep/v2/01001000").quota,
+            Quota::per_second(100)
+        );
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    mem::discriminant,
+    time::Duration,
+};
+
+use crate::error::{Error, Errored};
+
+/// Política de novas tentativas aplicada a todas as requisições do cliente.
+///
+/// O intervalo entre tentativas cresce exponencialmente a partir de `initial_backoff`,
+/// limitado por `max_backoff`, com jitter aleatório para evitar que vários clientes
+/// tentem novamente ao mesmo tempo. Quando a resposta traz o header `Retry-After`,
+/// o intervalo indicado pelo servidor é respeitado.
+///
+/// # Exemplo
+/// ```rust
+/// use std::time::Duration;
+/// use brasilapi::client::{BrasilApiClient, RetryPolicy};
+///
+/// let client = BrasilApiClient::builder()
+///     .retry(
+///         RetryPolicy::new()
+///             .max_attempts(5)
+///             .backoff(Duration::from_millis(200), Duration::from_secs(10)),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    jitter: bool,
+    respect_retry_after: bool,
+    retry_on: Option<Vec<Errored>>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Até 3 tentativas, começando com 500ms de espera e no máximo 30s.
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+            retry_on: None,
+        }
+    }
+
+    /// Nenhuma nova tentativa. É a política padrão do cliente.
+    pub fn none() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /// Número total de tentativas, incluindo a primeira.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Espera antes da segunda tentativa e o limite para as esperas seguintes.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+
+    /// Habilita ou desabilita o jitter aleatório na espera.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Define se o header `Retry-After` deve ser respeitado.
+    ///
+    /// Quando o servidor pede uma espera maior que `max_backoff`, a requisição não é
+    /// repetida e o erro é retornado.
+    pub fn respect_retry_after(mut self, respect: bool) -> Self {
+        self.respect_retry_after = respect;
+        self
+    }
+
+    /// Tipos de erro que devem ser repetidos.
+    ///
+    /// Por padrão são repetidos os erros em que [`Error::is_retryable`] é verdadeiro.
+    pub fn retry_on(mut self, kinds: Vec<Errored>) -> Self {
+        self.retry_on = Some(kinds);
+        self
+    }
+
+    pub(crate) fn is_retryable(&self, error: &Error) -> bool {
+        match &self.retry_on {
+            Some(kinds) => kinds
+                .iter()
+                .any(|kind| discriminant(kind) == discriminant(&error.error)),
+            None => error.is_retryable(),
+        }
+    }
+
+    /// Tempo de espera antes da próxima tentativa, ou `None` se a requisição não deve
+    /// ser repetida.
+    pub(crate) fn next_delay(
+        &self,
+        error: &Error,
+        attempt: u32,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error) {
+            return None;
+        }
+
+        match retry_after {
+            Some(retry_after) if self.respect_retry_after => {
+                (retry_after <= self.max_backoff).then_some(retry_after)
+            }
+            _ => Some(self.backoff_delay(attempt)),
+        }
+    }
+
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            // "Equal jitter": metade fixa, metade aleatória.
+            let half = delay / 2;
+            half + half.mul_f64(random_fraction())
+        } else {
+            delay
+        }
+    }
+}
+
+/// Interpreta o header `Retry-After` no formato de segundos.
+pub(crate) fn parse_retry_after(value: Option<&str>) -> Option<Duration> {
+    value?.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// Número pseudoaleatório em `[0, 1)`, suficiente para jitter.
+fn random_fraction() -> f64 {
+    let value = RandomState::new().build_hasher().finish();
+
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use super::*;
+
+    fn error(kind: Errored) -> Error {
+        Error::new(String::new(), kind, None)
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_max() {
+        let policy = RetryPolicy::new()
+            .max_attempts(10)
+            .jitter(false)
+            .backoff(Duration::from_millis(100), Duration::from_millis(500));
+        let unavailable = error(Errored::ServiceUnavailable);
+
+        let delays: Vec<_> = (1..6)
+            .map(|attempt| policy.next_delay(&unavailable, attempt, None).unwrap())
+            .collect();
+
+        assert_eq!(
+            delays,
+            [100, 200, 400, 500, 500]
+                .map(Duration::from_millis)
+                .to_vec()
+        );
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let policy = RetryPolicy::new().backoff(Duration::from_millis(400), Duration::from_secs(1));
+        let delay = policy
+            .next_delay(&error(Errored::Timeout), 1, None)
+            .unwrap();
+
+        assert!(delay >= Duration::from_millis(200) && delay <= Duration::from_millis(400));
+    }
+
+    #[test]
+    fn stops_after_max_attempts_and_on_non_retryable_errors() {
+        let policy = RetryPolicy::new().max_attempts(2);
+
+        assert!(policy
+            .next_delay(&error(Errored::BadGateway), 1, None)
+            .is_some());
+        assert!(policy
+            .next_delay(&error(Errored::BadGateway), 2, None)
+            .is_none());
+        assert!(policy
+            .next_delay(&error(Errored::NotFound), 1, None)
+            .is_none());
+    }
+
+    #[test]
+    fn retry_on_overrides_default_kinds() {
+        let policy = RetryPolicy::new().retry_on(vec![Errored::NotFound]);
+
+        assert!(policy.is_retryable(&error(Errored::NotFound)));
+        assert!(!policy.is_retryable(&error(Errored::ServiceUnavailable)));
+    }
+
+    #[test]
+    fn retry_after_is_respected_up_to_max_backoff() {
+        let policy = RetryPolicy::new().backoff(Duration::from_millis(100), Duration::from_secs(5));
+        let limited = error(Errored::TooManyRequests);
+
+        assert_eq!(
+            policy.next_delay(&limited, 1, Some(Duration::from_secs(2))),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(
+            policy.next_delay(&limited, 1, Some(Duration::from_secs(60))),
+            None
+        );
+    }
+}
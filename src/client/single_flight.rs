@@ -0,0 +1,214 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    task::{Poll, Waker},
+};
+
+use super::Response;
+use crate::error::Error;
+
+type Outcome = Result<Response, Error>;
+
+/// Agrupa requisições idênticas feitas ao mesmo tempo em uma única requisição.
+///
+/// A primeira chamada para um caminho executa a requisição; as chamadas que chegam
+/// enquanto ela está em andamento aguardam e recebem uma cópia do mesmo resultado. Se a
+/// primeira chamada for cancelada, cada chamada em espera faz a sua própria requisição.
+#[derive(Debug, Default)]
+pub(crate) struct SingleFlight {
+    flights: Mutex<HashMap<String, Arc<Flight>>>,
+}
+
+#[derive(Debug, Default)]
+struct Flight {
+    state: Mutex<FlightState>,
+}
+
+#[derive(Debug, Default)]
+struct FlightState {
+    /// `Some(None)` quando a chamada que fazia a requisição foi cancelada.
+    outcome: Option<Option<Outcome>>,
+    wakers: Vec<Waker>,
+}
+
+impl SingleFlight {
+    /// Executa `request` para `key`, ou aguarda a execução em andamento para a mesma chave.
+    pub(crate) async fn run<F>(&self, key: &str, request: impl FnOnce() -> F) -> Outcome
+    where
+        F: Future<Output = Outcome>,
+    {
+        let (flight, leader) = {
+            let mut flights = self.lock();
+            match flights.get(key) {
+                Some(flight) => (flight.clone(), false),
+                None => {
+                    let flight = Arc::new(Flight::default());
+                    flights.insert(key.to_string(), flight.clone());
+                    (flight, true)
+                }
+            }
+        };
+
+        if !leader {
+            return match flight.wait().await {
+                Some(outcome) => outcome,
+                None => request().await,
+            };
+        }
+
+        let mut guard = Leader {
+            single_flight: self,
+            key,
+            flight,
+            outcome: None,
+        };
+        let outcome = request().await;
+        guard.outcome = Some(outcome.clone());
+
+        outcome
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<Flight>>> {
+        self.flights.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Flight {
+    fn lock(&self) -> std::sync::MutexGuard<'_, FlightState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn wait(&self) -> Option<Outcome> {
+        std::future::poll_fn(|cx| {
+            let mut state = self.lock();
+            match &state.outcome {
+                Some(outcome) => Poll::Ready(outcome.clone()),
+                None => {
+                    if !state.wakers.iter().any(|w| w.will_wake(cx.waker())) {
+                        state.wakers.push(cx.waker().clone());
+                    }
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
+
+/// Publica o resultado da chamada que fez a requisição ao sair de escopo, inclusive
+/// quando ela é cancelada.
+struct Leader<'a> {
+    single_flight: &'a SingleFlight,
+    key: &'a str,
+    flight: Arc<Flight>,
+    outcome: Option<Outcome>,
+}
+
+impl Drop for Leader<'_> {
+    fn drop(&mut self) {
+        {
+            let mut flights = self.single_flight.lock();
+            if flights
+                .get(self.key)
+                .is_some_and(|flight| Arc::ptr_eq(flight, &self.flight))
+            {
+                flights.remove(self.key);
+            }
+        }
+
+        let wakers = {
+            let mut state = self.flight.lock();
+            state.outcome = Some(self.outcome.take());
+            std::mem::take(&mut state.wakers)
+        };
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+#[cfg(test)]
+mod single_flight_tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::client::{BrasilApiClient, InMemoryTransport};
+
+    #[tokio::test]
+    async fn concurrent_identical_requests_share_one_call() {
+        let transport = InMemoryTransport::new()
+            .route("/api/ddd/v1/61", 200, r#"{"state": "DF", "cities": []}"#)
+            .delay("/api/ddd/v1/61", Duration::from_millis(20))
+            .route("/api/ddd/v1/11", 200, r#"{"state": "SP", "cities": []}"#);
+        let client = BrasilApiClient::with_transport(transport.clone());
+        let clone = client.clone();
+
+        let (a, b, c, other) = tokio::join!(
+            client.get("ddd.get", "/api/ddd/v1/61"),
+            client.get("ddd.get", "/api/ddd/v1/61"),
+            clone.get("ddd.get", "/api/ddd/v1/61"),
+            client.get("ddd.get", "/api/ddd/v1/11"),
+        );
+
+        assert_eq!(a.unwrap().body, b.as_ref().unwrap().body);
+        assert_eq!(b.unwrap().body, c.unwrap().body);
+        assert!(other.is_ok());
+        assert_eq!(transport.requests().len(), 2);
+
+        // Depois de terminada, a próxima chamada faz uma nova requisição.
+        client.get("ddd.get", "/api/ddd/v1/61").await.unwrap();
+        assert_eq!(transport.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn waiters_request_on_their_own_when_leader_is_cancelled() {
+        let single_flight = SingleFlight::default();
+        let ok = || async {
+            Ok(Response {
+                status: 200,
+                body: "{}".to_string(),
+            })
+        };
+
+        let mut leader = Box::pin(single_flight.run("/api/ddd/v1/61", || async {
+            futures_timer::Delay::new(Duration::from_secs(60)).await;
+            ok().await
+        }));
+        let mut waiter = Box::pin(single_flight.run("/api/ddd/v1/61", ok));
+
+        assert!(futures::poll!(&mut leader).is_pending());
+        assert!(futures::poll!(&mut waiter).is_pending());
+        drop(leader);
+
+        assert!(waiter.await.is_ok());
+        assert!(single_flight.lock().is_empty());
+    }
+
+    #[test]
+    fn coalescing_can_be_disabled() {
+        let client = BrasilApiClient::builder()
+            .coalesce_requests(false)
+            .build_with_transport(InMemoryTransport::new());
+
+        assert!(client.single_flight.is_none());
+    }
+
+    #[test]
+    fn coalescing_is_opt_in_with_middleware() {
+        struct Noop;
+        impl crate::client::Middleware for Noop {}
+
+        let client = BrasilApiClient::builder()
+            .middleware(Noop)
+            .build_with_transport(InMemoryTransport::new());
+        assert!(client.single_flight.is_none());
+
+        let client = BrasilApiClient::builder()
+            .middleware(Noop)
+            .coalesce_requests(true)
+            .build_with_transport(InMemoryTransport::new());
+        assert!(client.single_flight.is_some());
+    }
+}
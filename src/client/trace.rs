@@ -0,0 +1,249 @@
+//! Instrumentação das requisições com `tracing`, habilitada pela feature `tracing`.
+//!
+//! Sem a feature, o [`RequestSpan`] não faz nada e é removido pelo compilador.
+use std::{future::Future, time::Duration};
+
+#[cfg(feature = "tracing")]
+use web_time::Instant;
+
+use super::Response;
+use crate::error::Error;
+
+/// Span de uma operação do cliente, como `cep.get` ou `fipe.get_vehicles`.
+///
+/// Os campos `status`, `latency_ms`, `attempt` e `cache` são preenchidos ao longo da
+/// requisição.
+pub(crate) struct RequestSpan {
+    #[cfg(feature = "tracing")]
+    span: tracing::Span,
+    #[cfg(feature = "tracing")]
+    started: Instant,
+}
+
+impl RequestSpan {
+    pub(crate) fn new(operation: &'static str, endpoint: &str, path: &str) -> Self {
+        #[cfg(not(feature = "tracing"))]
+        let _ = (operation, endpoint, path);
+
+        Self {
+            #[cfg(feature = "tracing")]
+            span: tracing::info_span!(
+                "brasilapi.request",
+                operation,
+                endpoint,
+                path,
+                status = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+                attempt = tracing::field::Empty,
+                cache = tracing::field::Empty,
+            ),
+            #[cfg(feature = "tracing")]
+            started: Instant::now(),
+        }
+    }
+
+    /// Executa `future` dentro do span.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> impl Future<Output = F::Output> {
+        tracing::Instrument::instrument(future, self.span.clone())
+    }
+
+    /// Executa `future` dentro do span.
+    #[cfg(not(feature = "tracing"))]
+    pub(crate) fn instrument<F: Future>(&self, future: F) -> F {
+        future
+    }
+
+    /// Registra o resultado da consulta ao cache: `hit`, `miss` ou `offline`.
+    pub(crate) fn cache(&self, cache: &'static str) {
+        #[cfg(feature = "tracing")]
+        self.span.record("cache", cache);
+        #[cfg(not(feature = "tracing"))]
+        let _ = cache;
+    }
+
+    /// Registra a URL base para a qual a requisição está sendo enviada.
+    pub(crate) fn endpoint(&self, base_url: &str) {
+        #[cfg(feature = "tracing")]
+        self.span.record("endpoint", base_url);
+        #[cfg(not(feature = "tracing"))]
+        let _ = base_url;
+    }
+
+    /// Registra o número da tentativa em andamento, começando em 1.
+    pub(crate) fn attempt(&self, attempt: u32) {
+        #[cfg(feature = "tracing")]
+        self.span.record("attempt", attempt);
+        #[cfg(not(feature = "tracing"))]
+        let _ = attempt;
+    }
+
+    /// Emite um evento para uma tentativa que falhou e será repetida após `delay`.
+    pub(crate) fn retry(&self, error: &Error, attempt: u32, delay: Duration) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            parent: &self.span,
+            attempt,
+            status = error.code,
+            kind = ?error.error,
+            delay_ms = delay.as_millis() as u64,
+            "BrasilAPI request failed, retrying: {error}"
+        );
+        #[cfg(not(feature = "tracing"))]
+        let _ = (error, attempt, delay);
+    }
+
+    /// Registra o status e a latência e emite um evento se a requisição falhou.
+    pub(crate) fn finish(&self, result: &Result<Response, Error>) {
+        #[cfg(feature = "tracing")]
+        {
+            self.span
+                .record("latency_ms", self.started.elapsed().as_millis() as u64);
+
+            match result {
+                Ok(response) => {
+                    self.span.record("status", response.status);
+                }
+                Err(error) => {
+                    if let Some(status) = error.code {
+                        self.span.record("status", status);
+                    }
+
+                    // 404 é uma resposta esperada (CEP inexistente, validações, ...).
+                    if error.error == crate::error::Errored::NotFound {
+                        tracing::debug!(parent: &self.span, kind = ?error.error, "BrasilAPI request failed: {error}");
+                    } else {
+                        tracing::warn!(parent: &self.span, kind = ?error.error, "BrasilAPI request failed: {error}");
+                    }
+                }
+            }
+        }
+        #[cfg(not(feature = "tracing"))]
+        let _ = result;
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod trace_tests {
+    use std::{
+        collections::HashMap,
+        fmt,
+        sync::{
+            atomic::{AtomicU64, Ordering},
+            Arc, Mutex,
+        },
+    };
+
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Metadata, Subscriber,
+    };
+
+    use crate::client::{BrasilApiClient, Cache, InMemoryTransport};
+
+    type Fields = HashMap<String, String>;
+
+    /// Subscriber mínimo que guarda os campos de spans e eventos.
+    #[derive(Clone, Default)]
+    struct Recorder {
+        next_id: Arc<AtomicU64>,
+        spans: Arc<Mutex<HashMap<u64, Fields>>>,
+        events: Arc<Mutex<Vec<Fields>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &span::Attributes<'_>) -> span::Id {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+            let mut fields = Fields::new();
+            attributes.record(&mut FieldVisitor(&mut fields));
+            self.spans.lock().unwrap().insert(id, fields);
+
+            span::Id::from_u64(id)
+        }
+
+        fn record(&self, id: &span::Id, values: &span::Record<'_>) {
+            let mut spans = self.spans.lock().unwrap();
+            values.record(&mut FieldVisitor(spans.get_mut(&id.into_u64()).unwrap()));
+        }
+
+        fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::new();
+            event.record(&mut FieldVisitor(&mut fields));
+            self.events.lock().unwrap().push(fields);
+        }
+
+        fn enter(&self, _: &span::Id) {}
+
+        fn exit(&self, _: &span::Id) {}
+    }
+
+    #[test]
+    fn spans_record_operation_status_and_cache() {
+        let recorder = Recorder::default();
+        let transport = InMemoryTransport::new()
+            .route(
+                "/api/taxas/v1/SELIC",
+                200,
+                r#"{"nome": "Selic", "valor": 13.75}"#,
+            )
+            .route("/api/taxas/v1/XYZ", 500, "Internal Server Error");
+        let client = BrasilApiClient::builder()
+            .base_url("http://brasilapi.test")
+            .cache(Cache::new(10))
+            .build_with_transport(transport);
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            futures::executor::block_on(async {
+                client
+                    .get("taxas.get", "/api/taxas/v1/SELIC")
+                    .await
+                    .unwrap();
+                client
+                    .get("taxas.get", "/api/taxas/v1/SELIC")
+                    .await
+                    .unwrap();
+                client
+                    .get("taxas.get", "/api/taxas/v1/XYZ")
+                    .await
+                    .unwrap_err();
+            })
+        });
+
+        let spans = recorder.spans.lock().unwrap();
+        let (first, cached, failed) = (&spans[&1], &spans[&2], &spans[&3]);
+
+        assert_eq!(first["operation"], "taxas.get");
+        assert_eq!(first["endpoint"], "http://brasilapi.test");
+        assert_eq!(first["path"], "/api/taxas/v1/SELIC");
+        assert_eq!(first["status"], "200");
+        assert_eq!(first["attempt"], "1");
+        assert_eq!(first["cache"], "miss");
+        assert!(first.contains_key("latency_ms"));
+        assert_eq!(cached["cache"], "hit");
+        assert!(!cached.contains_key("attempt"));
+        assert_eq!(failed["status"], "500");
+
+        let events = recorder.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["kind"], "InternalServerError");
+    }
+}
@@ -0,0 +1,356 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::error::{Error, Errored};
+
+/// Requisição HTTP enviada por um [`Transport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    pub fn get(url: &str) -> Self {
+        Self {
+            method: "GET".to_string(),
+            url: url.to_string(),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Caminho da URL, incluindo a query string (`/api/cep/v2/01001000`).
+    pub fn path(&self) -> &str {
+        let without_scheme = match self.url.find("://") {
+            Some(position) => &self.url[position + 3..],
+            None => &self.url,
+        };
+
+        match without_scheme.find('/') {
+            Some(position) => &without_scheme[position..],
+            None => "/",
+        }
+    }
+}
+
+/// Resposta HTTP retornada por um [`Transport`].
+///
+/// Respostas com status de erro (404, 500, ...) também são retornadas aqui; o cliente
+/// é quem as converte em [`Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: body.into(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Valor de um header, sem diferenciar maiúsculas de minúsculas no nome.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// `Send` em todos os alvos, exceto no wasm32, onde as futures do `fetch` do navegador
+/// não são `Send`.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSend: Send {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+
+/// `Send` em todos os alvos, exceto no wasm32, onde as futures do `fetch` do navegador
+/// não são `Send`.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSend {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// `Sync` em todos os alvos, exceto no wasm32.
+#[cfg(not(target_arch = "wasm32"))]
+pub trait MaybeSync: Sync {}
+#[cfg(not(target_arch = "wasm32"))]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+
+/// `Sync` em todos os alvos, exceto no wasm32.
+#[cfg(target_arch = "wasm32")]
+pub trait MaybeSync {}
+#[cfg(target_arch = "wasm32")]
+impl<T: ?Sized> MaybeSync for T {}
+
+/// Camada que efetivamente envia as requisições do [`BrasilApiClient`](super::BrasilApiClient).
+///
+/// O cliente usa o [`ReqwestTransport`] por padrão. Implementações próprias permitem
+/// testar sem rede (veja [`InMemoryTransport`]) ou usar outro cliente HTTP.
+///
+/// Erros de transporte (conexão, timeout, TLS) devem ser retornados como [`Error`]
+/// com o [`Errored`] correspondente.
+///
+/// # Runtimes
+/// O único transporte de rede incluído na biblioteca é o [`ReqwestTransport`], que
+/// precisa de um runtime tokio. Em async-std, smol ou outros executores é preciso
+/// implementar este trait com um cliente HTTP compatível com o runtime; retry, rate
+/// limit, cache e deadline do cliente funcionam em qualquer executor.
+///
+/// ```rust,no_run
+/// use brasilapi::client::{BrasilApiClient, HttpRequest, HttpResponse, Transport};
+/// use brasilapi::error::{Error, Errored};
+///
+/// # async fn http_get(url: &str) -> std::io::Result<(u16, Vec<u8>)> { unimplemented!() }
+/// // `http_get` representa o cliente HTTP do runtime utilizado.
+/// struct RuntimeTransport;
+///
+/// impl Transport for RuntimeTransport {
+///     async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+///         let (status, body) = http_get(&request.url)
+///             .await
+///             .map_err(|e| Error::new(e.to_string(), Errored::Connection, None))?;
+///
+///         Ok(HttpResponse::new(status, body))
+///     }
+/// }
+///
+/// let client = BrasilApiClient::with_transport(RuntimeTransport);
+/// ```
+pub trait Transport: MaybeSend + MaybeSync + 'static {
+    fn send(
+        &self,
+        request: HttpRequest,
+    ) -> impl Future<Output = Result<HttpResponse, Error>> + MaybeSend;
+}
+
+/// Transporte padrão, baseado no `reqwest`.
+///
+/// O `reqwest` depende do reactor do tokio, então as requisições feitas por este
+/// transporte precisam rodar dentro de um runtime tokio (um runtime de uma única thread
+/// é suficiente). Em outros executores, como async-std ou smol, utilize um [`Transport`]
+/// próprio. No wasm32 o `reqwest` usa o `fetch` do navegador e não precisa do tokio.
+///
+/// O [`Default`] usa a configuração padrão do
+/// [`BrasilApiClientBuilder`](super::BrasilApiClientBuilder): `User-Agent` da biblioteca e
+/// o backend TLS escolhido pelas features.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    http: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(http: reqwest::Client) -> Self {
+        Self { http }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        super::BrasilApiClientBuilder::new()
+            .reqwest_transport()
+            .expect("the default client configuration is always valid")
+    }
+}
+
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let method = reqwest::Method::from_bytes(request.method.as_bytes())
+            .map_err(|e| Error::new(e.to_string(), Errored::Unexpected, None))?;
+
+        let mut builder = self.http.request(method, &request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.send().await.map_err(Error::from_error)?;
+        let status = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+
+        let body = response
+            .bytes()
+            .await
+            .map_err(|e| Error::new(e.to_string(), Errored::Body, Some(status)).with_source(e))?;
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body: body.to_vec(),
+        })
+    }
+}
+
+/// Transporte em memória, que responde a partir de rotas pré-definidas sem usar a rede.
+///
+/// Rotas não cadastradas respondem 404. Todas as requisições recebidas ficam
+/// registradas e podem ser consultadas com [`InMemoryTransport::requests`], e
+/// [`InMemoryTransport::delay`] simula respostas lentas.
+///
+/// # Exemplo
+/// ```rust
+/// use brasilapi::client::{BrasilApiClient, InMemoryTransport};
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let transport = InMemoryTransport::new().route(
+///         "/api/ddd/v1/61",
+///         200,
+///         r#"{"state": "DF", "cities": ["BRASÍLIA"]}"#,
+///     );
+///
+///     let client = BrasilApiClient::with_transport(transport.clone());
+///     let ddd = client.get_ddd("61").await.unwrap();
+///
+///     assert_eq!(ddd.state, "DF");
+///     assert_eq!(transport.requests().len(), 1);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryTransport {
+    routes: Arc<Mutex<HashMap<String, HttpResponse>>>,
+    delays: Arc<Mutex<HashMap<String, Duration>>>,
+    requests: Arc<Mutex<Vec<HttpRequest>>>,
+}
+
+impl InMemoryTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Responde `body` com o status `status` para o caminho `path` (incluindo a query string).
+    pub fn route(self, path: &str, status: u16, body: &str) -> Self {
+        self.respond(path, HttpResponse::new(status, body))
+    }
+
+    /// Responde `response` para o caminho `path` (incluindo a query string).
+    pub fn respond(self, path: &str, response: HttpResponse) -> Self {
+        self.routes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(path.to_string(), response);
+        self
+    }
+
+    /// Aguarda `delay` antes de responder às requisições para o caminho `path`.
+    pub fn delay(self, path: &str, delay: Duration) -> Self {
+        self.delays
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(path.to_string(), delay);
+        self
+    }
+
+    /// Requisições recebidas até o momento, em ordem.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+impl Transport for InMemoryTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, Error> {
+        let response = self
+            .routes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(request.path())
+            .cloned();
+        let delay = self
+            .delays
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(request.path())
+            .copied();
+
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request);
+
+        if let Some(delay) = delay {
+            super::sleep(delay).await;
+        }
+
+        Ok(response.unwrap_or_else(|| {
+            HttpResponse::new(
+                404,
+                r#"{"name": "NotFoundError", "message": "Rota não cadastrada no InMemoryTransport", "type": "not_found"}"#,
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod transport_tests {
+    use super::*;
+
+    #[test]
+    fn request_path_includes_query() {
+        let request = HttpRequest::get("https://brasilapi.com.br/api/fipe/marcas/v1/carros?x=1");
+
+        assert_eq!(request.path(), "/api/fipe/marcas/v1/carros?x=1");
+        assert_eq!(HttpRequest::get("http://localhost:3000").path(), "/");
+    }
+
+    #[test]
+    fn header_lookup_is_case_insensitive() {
+        let response = HttpResponse::new(429, "").with_header("Retry-After", "10");
+
+        assert_eq!(response.header("retry-after"), Some("10"));
+        assert_eq!(response.header("x-missing"), None);
+    }
+
+    #[tokio::test]
+    async fn in_memory_transport_records_requests_and_defaults_to_not_found() {
+        let transport = InMemoryTransport::new().route("/api/banks/v1", 200, "[]");
+
+        let ok = transport
+            .send(HttpRequest::get("http://test/api/banks/v1"))
+            .await
+            .unwrap();
+        let missing = transport
+            .send(HttpRequest::get("http://test/api/banks/v1/999"))
+            .await
+            .unwrap();
+
+        assert_eq!(ok, HttpResponse::new(200, "[]"));
+        assert_eq!(missing.status, 404);
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn in_memory_transport_delays_responses() {
+        let transport = InMemoryTransport::new()
+            .route("/api/taxas/v1", 200, "[]")
+            .delay("/api/taxas/v1", Duration::from_millis(50));
+
+        let started = std::time::Instant::now();
+        transport
+            .send(HttpRequest::get("http://test/api/taxas/v1"))
+            .await
+            .unwrap();
+
+        assert!(started.elapsed() >= Duration::from_millis(50));
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{error::*, spec::BRASIL_API_URL};
+use crate::{
+    client::{batch, default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::*,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -62,25 +65,41 @@ pub struct Qsa {
     pub codigo_qualificacao_representante_legal: Option<i32>,
 }
 
-pub struct CnpjService {
-    base_url: String,
+pub struct CnpjService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl CnpjService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_cnpj_request(&self, cnpj_code: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/cnpj/v1/{}", self.base_url, cnpj_code);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> CnpjService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
+
+    async fn get_cnpj_request(&self, cnpj_code: &str) -> Result<Response, Error> {
+        self.client
+            .get("cnpj.get", &format!("/api/cnpj/v1/{cnpj_code}"))
+            .await
+    }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Busca por CNPJ utilizando este cliente. Veja [`get_cnpj`].
+    pub async fn get_cnpj(&self, cnpj: &str) -> Result<Cnpj, Error> {
+        let response = CnpjService::with_client(self)
+            .get_cnpj_request(cnpj)
+            .await?;
+
+        response.json()
+    }
 }
 
 /// #### `get_cnpj(cnpj: &str)`
@@ -102,14 +121,15 @@ impl CnpjService {
 /// }
 /// ```
 pub async fn get_cnpj(cnpj: &str) -> Result<Cnpj, Error> {
-    let cnpj_service = CnpjService::new(BRASIL_API_URL);
-
-    let response = cnpj_service.get_cnpj_request(cnpj).await?;
-
-    let body = response.text().await.unwrap();
-    let cnpj: Cnpj = serde_json::from_str(&body).unwrap();
+    default_client().get_cnpj(cnpj).await
+}
 
-    Ok(cnpj)
+batch::batch_lookups! {
+    module: cnpj,
+    item: Cnpj,
+    noun: "CNPJ",
+    client: get_cnpjs, stream_cnpjs => get_cnpj,
+    example: r#"["00000000000191", "33000167000101"]"#,
 }
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{error::Error, spec::BRASIL_API_URL};
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::Error,
+};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Corretora {
@@ -23,33 +26,55 @@ pub struct Corretora {
     pub valor_patrimonio_liquido: String,
 }
 
-pub struct CorretorasService {
-    url: String,
+pub struct CorretorasService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl CorretorasService {
     pub fn new(url: &str) -> Self {
         Self {
-            url: url.to_string(),
+            client: BrasilApiClient::with_base_url(url),
         }
     }
+}
 
-    async fn get_corretoras_request(&self) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/cvm/corretoras/v1", self.url);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> CorretorasService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
-    async fn get_corretora_by_cnpj(&self, cnpj: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/cvm/corretoras/v1/{}", self.url, cnpj);
+    async fn get_corretoras_request(&self) -> Result<Response, Error> {
+        self.client
+            .get("corretoras.list", "/api/cvm/corretoras/v1")
+            .await
+    }
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+    async fn get_corretora_by_cnpj(&self, cnpj: &str) -> Result<Response, Error> {
+        self.client
+            .get("corretoras.get", &format!("/api/cvm/corretoras/v1/{cnpj}"))
+            .await
+    }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Retorna as corretoras da CVM utilizando este cliente. Veja [`get_corretoras`].
+    pub async fn get_corretoras(&self) -> Result<Vec<Corretora>, Error> {
+        let response = CorretorasService::with_client(self)
+            .get_corretoras_request()
+            .await?;
+
+        response.json()
+    }
+
+    /// Busca uma corretora pelo CNPJ utilizando este cliente. Veja [`get_corretora`].
+    pub async fn get_corretora(&self, cnpj: &str) -> Result<Corretora, Error> {
+        let response = CorretorasService::with_client(self)
+            .get_corretora_by_cnpj(cnpj)
+            .await?;
+
+        response.json()
     }
 }
 
@@ -70,14 +95,7 @@ impl CorretorasService {
 ///
 /// ```
 pub async fn get_corretoras() -> Result<Vec<Corretora>, Error> {
-    let corretoras_service = CorretorasService::new(BRASIL_API_URL);
-
-    let response = corretoras_service.get_corretoras_request().await?;
-
-    let body = response.text().await.unwrap();
-    let corretoras: Vec<Corretora> = serde_json::from_str(&body).unwrap();
-
-    Ok(corretoras)
+    default_client().get_corretoras().await
 }
 
 /// #### `get_corretora(cnpj: &str)`
@@ -100,14 +118,7 @@ pub async fn get_corretoras() -> Result<Vec<Corretora>, Error> {
 ///
 /// ```
 pub async fn get_corretora(cnpj: &str) -> Result<Corretora, Error> {
-    let corretoras_service = CorretorasService::new(BRASIL_API_URL);
-
-    let response = corretoras_service.get_corretora_by_cnpj(cnpj).await?;
-
-    let body = response.text().await.unwrap();
-    let corretora: Corretora = serde_json::from_str(&body).unwrap();
-
-    Ok(corretora)
+    default_client().get_corretora(cnpj).await
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use crate::{error::*, spec::BRASIL_API_URL};
+use crate::{
+    client::{batch, default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::*,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -16,26 +19,31 @@ pub struct Regiao {
     pub nome: String,
 }
 
-pub struct DDDService {
-    base_url: String,
+pub struct DDDService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl DDDService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_ddd_request(&self, ddd: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/ddd/v1/{}", self.base_url, ddd);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> DDDService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
+    async fn get_ddd_request(&self, ddd: &str) -> Result<Response, Error> {
+        self.client
+            .get("ddd.get", &format!("/api/ddd/v1/{ddd}"))
+            .await
+    }
+
     async fn validate_ddd(&self, ddd: &str) -> Result<bool, Error> {
         let response = self.get_ddd_request(ddd).await;
 
@@ -49,6 +57,20 @@ impl DDDService {
     }
 }
 
+impl<T: Transport> BrasilApiClient<T> {
+    /// Retorna estado e cidades de um DDD utilizando este cliente. Veja [`get_ddd`].
+    pub async fn get_ddd(&self, ddd: &str) -> Result<Ddd, Error> {
+        let response = DDDService::with_client(self).get_ddd_request(ddd).await?;
+
+        response.json()
+    }
+
+    /// Verifica se um DDD existe utilizando este cliente. Veja [`ddd_exists`].
+    pub async fn ddd_exists(&self, ddd: &str) -> Result<bool, Error> {
+        DDDService::with_client(self).validate_ddd(ddd).await
+    }
+}
+
 /// #### `get_ddd(ddd: &str)`
 /// Retorna estado e lista de cidades por DDD
 ///
@@ -68,14 +90,7 @@ impl DDDService {
 /// }
 /// ```
 pub async fn get_ddd(ddd: &str) -> Result<Ddd, Error> {
-    let ddd_service = DDDService::new(BRASIL_API_URL);
-
-    let response = ddd_service.get_ddd_request(ddd).await?;
-
-    let body = response.text().await.unwrap();
-    let ddd: Ddd = serde_json::from_str(&body).unwrap();
-
-    Ok(ddd)
+    default_client().get_ddd(ddd).await
 }
 
 /// #### `ddd_exists(ddd: &str)`
@@ -97,16 +112,21 @@ pub async fn get_ddd(ddd: &str) -> Result<Ddd, Error> {
 /// }
 /// ```
 pub async fn ddd_exists(ddd: &str) -> Result<bool, Error> {
-    let ddd_service = DDDService::new(BRASIL_API_URL);
-
-    let response = ddd_service.validate_ddd(ddd).await?;
+    default_client().ddd_exists(ddd).await
+}
 
-    Ok(response)
+batch::batch_lookups! {
+    module: ddd,
+    item: Ddd,
+    noun: "DDD",
+    client: get_ddds, stream_ddds => get_ddd,
+    example: r#"["11", "61"]"#,
 }
 
 #[cfg(test)]
 mod ddd_tests {
     use super::*;
+    use crate::client::InMemoryTransport;
 
     #[tokio::test]
     async fn get_ddd_test() {
@@ -128,4 +148,20 @@ mod ddd_tests {
 
         assert!(ddd);
     }
+
+    #[tokio::test]
+    async fn ddd_with_in_memory_transport() {
+        let transport = InMemoryTransport::new().route(
+            "/api/ddd/v1/61",
+            200,
+            r#"{"state": "DF", "cities": ["BRASÍLIA", "BRAZLÂNDIA"]}"#,
+        );
+        let client = BrasilApiClient::with_transport(transport);
+
+        let ddd = client.get_ddd("61").await.unwrap();
+
+        assert_eq!(ddd.state, "DF");
+        assert_eq!(ddd.cities.len(), 2);
+        assert!(!client.ddd_exists("00").await.unwrap());
+    }
 }
@@ -1,3 +1,5 @@
+use std::{cmp::Ordering, fmt, sync::Arc};
+
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 
@@ -16,6 +18,43 @@ pub enum Errored {
     InternalServerError,
     BadRequest,
     Unexpected,
+    /// A requisição excedeu o tempo limite.
+    Timeout,
+    /// Não foi possível conectar ao servidor.
+    Connection,
+    /// Falha na negociação TLS (certificado inválido, handshake, etc.).
+    Tls,
+    /// Limite de requisições excedido (HTTP 429).
+    TooManyRequests,
+    /// HTTP 502.
+    BadGateway,
+    /// HTTP 503.
+    ServiceUnavailable,
+    /// HTTP 504.
+    GatewayTimeout,
+    /// O cliente está em modo offline e a resposta não está no cache.
+    Offline,
+    /// O valor informado é inválido (um CEP sem 8 dígitos, por exemplo) e nenhuma
+    /// requisição foi feita.
+    InvalidInput,
+    /// O circuit breaker do cliente está aberto para esta família de endpoints.
+    CircuitOpen,
+    /// Não foi possível ler o corpo da resposta.
+    ///
+    /// Só é repetido quando a leitura falhou por I/O ou conexão; um corpo que não é
+    /// UTF-8 válido falharia da mesma forma. Veja [`Error::is_retryable`].
+    Body,
+    /// O corpo da resposta não corresponde à estrutura esperada. Veja [`DecodeError`].
+    Decode(Box<DecodeError>),
+}
+
+/// Detalhes de um [`Errored::Decode`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct DecodeError {
+    /// Resposta original.
+    pub body: String,
+    /// Caminho até o campo que falhou (por exemplo `qsa[0].nome_socio`).
+    pub path: String,
 }
 
 impl Errored {
@@ -24,17 +63,113 @@ impl Errored {
             Some(StatusCode::NOT_FOUND) => Self::NotFound,
             Some(StatusCode::INTERNAL_SERVER_ERROR) => Self::InternalServerError,
             Some(StatusCode::BAD_REQUEST) => Self::BadRequest,
+            Some(StatusCode::TOO_MANY_REQUESTS) => Self::TooManyRequests,
+            Some(StatusCode::BAD_GATEWAY) => Self::BadGateway,
+            Some(StatusCode::SERVICE_UNAVAILABLE) => Self::ServiceUnavailable,
+            Some(StatusCode::GATEWAY_TIMEOUT) => Self::GatewayTimeout,
             _ => Self::Unexpected,
         }
     }
+
+    /// Indica se uma nova tentativa da mesma requisição pode ter sucesso.
+    ///
+    /// [`Errored::Body`] não entra aqui, pois depende da causa da falha; veja
+    /// [`Error::is_retryable`].
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Self::InternalServerError
+                | Self::Timeout
+                | Self::Connection
+                | Self::TooManyRequests
+                | Self::BadGateway
+                | Self::ServiceUnavailable
+                | Self::GatewayTimeout
+        )
+    }
+}
+
+impl fmt::Display for Errored {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::NotFound => "not found",
+            Self::InternalServerError => "internal server error",
+            Self::BadRequest => "bad request",
+            Self::Unexpected => "unexpected error",
+            Self::Timeout => "timed out",
+            Self::Connection => "connection error",
+            Self::Tls => "tls error",
+            Self::TooManyRequests => "too many requests",
+            Self::BadGateway => "bad gateway",
+            Self::ServiceUnavailable => "service unavailable",
+            Self::GatewayTimeout => "gateway timeout",
+            Self::Offline => "offline cache miss",
+            Self::CircuitOpen => "circuit open",
+            Self::InvalidInput => "invalid input",
+            Self::Body => "failed to read response body",
+            Self::Decode(_) => "failed to decode response",
+        };
+
+        f.write_str(kind)
+    }
+}
+
+/// Erro original que causou a falha.
+///
+/// Fica em um `Arc` para que [`Error`] continue `Clone`, e é ignorado nas comparações
+/// e na serialização.
+#[derive(Clone)]
+struct Source(Arc<dyn std::error::Error + Send + Sync>);
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
 }
 
+impl PartialEq for Source {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for Source {
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+/// Erro retornado pelas consultas.
+///
+/// O [`DecodeError`] fica em um `Box`, mas o [`BrasilAPIError`] e a mensagem ainda deixam
+/// o `Error` no limite do `clippy::result_large_err`. Para não mudar os campos públicos,
+/// o lint é liberado apenas nas funções internas que o acusam e no módulo `blocking`.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
 pub struct Error {
     pub code: Option<u16>,
     pub api_error: Option<BrasilAPIError>,
     pub message: String,
     pub error: Errored,
+
+    #[serde(skip)]
+    source: Option<Source>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "{} ({}): {}", self.error, code, self.message),
+            None => write!(f, "{}: {}", self.error, self.message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| source.0.as_ref() as &(dyn std::error::Error + 'static))
+    }
 }
 
 impl Error {
@@ -44,21 +179,90 @@ impl Error {
             code,
             message,
             error,
+            source: None,
         }
     }
 
+    /// Associa o erro original, retornado por [`std::error::Error::source`].
+    pub fn with_source<E>(mut self, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.source = Some(Source(Arc::new(source)));
+        self
+    }
+
     pub fn from_error(error: reqwest::Error) -> Self {
         let status = error.status();
-        let message = error.to_string();
 
-        let api_error: Option<BrasilAPIError> = serde_json::from_str(&error.to_string()).ok();
-        let error = Errored::status_code(status);
+        let kind = if error.is_timeout() {
+            Errored::Timeout
+        } else if is_tls_error(&error) {
+            Errored::Tls
+        } else if is_connect_error(&error) {
+            Errored::Connection
+        } else if error.is_body() || error.is_decode() {
+            Errored::Body
+        } else {
+            Errored::status_code(status)
+        };
+
+        Self::new(error.to_string(), kind, status.map(|s| s.as_u16())).with_source(error)
+    }
+
+    /// Indica se o recurso consultado não existe (HTTP 404).
+    pub fn is_not_found(&self) -> bool {
+        self.error == Errored::NotFound
+    }
+
+    /// Indica se uma nova tentativa da mesma requisição pode ter sucesso.
+    ///
+    /// Além dos tipos de [`Errored::is_retryable`], inclui os [`Errored::Body`] causados
+    /// por falhas de I/O ou de conexão durante a leitura do corpo.
+    pub fn is_retryable(&self) -> bool {
+        match self.error {
+            Errored::Body => self.is_io_failure(),
+            _ => self.error.is_retryable(),
+        }
+    }
+
+    /// Verifica se a cadeia de causas contém uma falha de I/O ou de conexão.
+    fn is_io_failure(&self) -> bool {
+        let mut source = std::error::Error::source(self);
+
+        while let Some(error) = source {
+            if error.is::<std::io::Error>() {
+                return true;
+            }
+
+            if let Some(error) = error.downcast_ref::<reqwest::Error>() {
+                if error.is_body() || error.is_timeout() {
+                    return true;
+                }
+            }
+
+            source = error.source();
+        }
+
+        false
+    }
+
+    /// Indica se a requisição excedeu o tempo limite.
+    pub fn is_timeout(&self) -> bool {
+        self.error == Errored::Timeout
+    }
+
+    /// Cria o erro correspondente a uma resposta com status diferente de 200.
+    pub fn from_status(status: u16, body: String) -> Self {
+        let api_error: Option<BrasilAPIError> = serde_json::from_str(&body).ok();
+        let error = Errored::status_code(StatusCode::from_u16(status).ok());
 
         Self {
-            code: status.map(|s| s.as_u16()),
-            message,
+            code: Some(status),
+            message: body,
             api_error,
             error,
+            source: None,
         }
     }
 
@@ -66,21 +270,212 @@ impl Error {
     pub async fn from_response(response: reqwest::Response) -> Result<reqwest::Response, Self> {
         let status = response.status();
 
-        let error = Errored::status_code(Some(status));
-
         match status {
             reqwest::StatusCode::OK => Ok(response),
             _ => {
-                let body = response.text().await.unwrap();
-                let api_error: Option<BrasilAPIError> = serde_json::from_str(&body).ok();
-
-                Err(Self {
-                    code: Some(status.as_u16()),
-                    message: body,
-                    api_error,
-                    error,
-                })
+                let body = match response.text().await {
+                    Ok(body) => body,
+                    Err(e) => e.to_string(),
+                };
+
+                Err(Self::from_status(status.as_u16(), body))
+            }
+        }
+    }
+
+    #[cfg(feature = "__client")]
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn decode_body<T: serde::de::DeserializeOwned>(
+        body: String,
+        code: Option<u16>,
+    ) -> Result<T, Self> {
+        let deserializer = &mut serde_json::Deserializer::from_str(&body);
+
+        match serde_path_to_error::deserialize(deserializer) {
+            Ok(value) => Ok(value),
+            Err(e) => {
+                let path = e.path().to_string();
+                let message = format!("failed to decode response at `{path}`: {}", e.inner());
+                let decode = DecodeError { body, path };
+                let error = Self::new(message, Errored::Decode(Box::new(decode)), code);
+
+                Err(error.with_source(e.into_inner()))
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn is_connect_error(error: &reqwest::Error) -> bool {
+    error.is_connect()
+}
+
+/// No wasm32 as falhas de rede do `fetch` chegam como erros de requisição.
+#[cfg(target_arch = "wasm32")]
+fn is_connect_error(error: &reqwest::Error) -> bool {
+    error.is_request()
+}
+
+/// Verifica se a cadeia de causas contém um erro do backend TLS habilitado.
+fn is_tls_error(error: &reqwest::Error) -> bool {
+    let mut source = std::error::Error::source(error);
+
+    while let Some(error) = source {
+        // O `source` de um `io::Error` pula o erro que ele embrulha, onde os conectores
+        // TLS colocam a falha, às vezes dentro de outro `io::Error`.
+        let mut wrapped = Some(error);
+        while let Some(error) = wrapped {
+            if is_tls_backend_error(error) {
+                return true;
             }
+
+            wrapped = error
+                .downcast_ref::<std::io::Error>()
+                .and_then(|io| io.get_ref())
+                .map(|inner| inner as &(dyn std::error::Error + 'static));
         }
+
+        source = error.source();
+    }
+
+    false
+}
+
+/// Indica se `error` é o tipo de erro de um dos backends TLS habilitados.
+#[allow(unused_variables)]
+fn is_tls_backend_error(error: &(dyn std::error::Error + 'static)) -> bool {
+    #[cfg(all(
+        any(feature = "default-tls", feature = "native-tls"),
+        not(target_arch = "wasm32")
+    ))]
+    if error.is::<native_tls_crate::Error>() {
+        return true;
+    }
+
+    #[cfg(all(feature = "rustls-tls", not(target_arch = "wasm32")))]
+    if error.is::<rustls::Error>() {
+        return true;
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    #[cfg(feature = "__client")]
+    #[derive(Debug, Deserialize)]
+    struct Item {
+        #[allow(dead_code)]
+        name: String,
+    }
+
+    #[cfg(feature = "__client")]
+    #[derive(Debug, Deserialize)]
+    struct Payload {
+        #[allow(dead_code)]
+        items: Vec<Item>,
+    }
+
+    #[cfg(feature = "__client")]
+    #[test]
+    fn decode_body_reports_path_and_raw_body() {
+        let body = r#"{"items": [{"name": "ok"}, {"name": 42}]}"#.to_string();
+
+        let error = Error::decode_body::<Payload>(body.clone(), Some(200)).unwrap_err();
+
+        assert_eq!(error.code, Some(200));
+        assert_eq!(
+            error.error,
+            Errored::Decode(Box::new(DecodeError {
+                body,
+                path: "items[1].name".to_string(),
+            }))
+        );
+    }
+
+    #[cfg(feature = "__client")]
+    #[test]
+    fn decode_body_malformed_json() {
+        let error = Error::decode_body::<Payload>("<html>".to_string(), Some(200)).unwrap_err();
+
+        assert!(matches!(error.error, Errored::Decode(_)));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn status_codes_map_to_kinds() {
+        let cases = [
+            (StatusCode::TOO_MANY_REQUESTS, Errored::TooManyRequests),
+            (StatusCode::BAD_GATEWAY, Errored::BadGateway),
+            (StatusCode::SERVICE_UNAVAILABLE, Errored::ServiceUnavailable),
+            (StatusCode::GATEWAY_TIMEOUT, Errored::GatewayTimeout),
+            (StatusCode::IM_A_TEAPOT, Errored::Unexpected),
+        ];
+
+        for (status, kind) in cases {
+            assert_eq!(Errored::status_code(Some(status)), kind);
+        }
+    }
+
+    #[test]
+    fn retryable_and_not_found_helpers() {
+        let not_found = Error::new("cep not found".to_string(), Errored::NotFound, Some(404));
+        let unavailable = Error::new(String::new(), Errored::ServiceUnavailable, Some(503));
+
+        assert!(not_found.is_not_found());
+        assert!(!not_found.is_retryable());
+        assert!(unavailable.is_retryable());
+        assert!(!unavailable.is_not_found());
+    }
+
+    #[test]
+    fn body_errors_are_retryable_only_for_io_failures() {
+        let invalid_utf8 = String::from_utf8(vec![0xff]).unwrap_err();
+        let decode = Error::new(invalid_utf8.to_string(), Errored::Body, Some(200))
+            .with_source(invalid_utf8);
+        let reset = std::io::Error::from(std::io::ErrorKind::ConnectionReset);
+        let io = Error::new(reset.to_string(), Errored::Body, Some(200)).with_source(reset);
+
+        assert!(!decode.is_retryable());
+        assert!(io.is_retryable());
+        assert!(!Errored::Body.is_retryable());
+    }
+
+    #[test]
+    fn display_includes_kind_code_and_message() {
+        let error = Error::new("cep not found".to_string(), Errored::NotFound, Some(404));
+
+        assert_eq!(error.to_string(), "not found (404): cep not found");
+    }
+
+    #[tokio::test]
+    async fn from_error_keeps_connection_errors_structured() {
+        // Porta reservada e sem servidor escutando.
+        let error = reqwest::get("http://127.0.0.1:9").await.unwrap_err();
+        let error = Error::from_error(error);
+
+        assert_eq!(error.error, Errored::Connection);
+        assert_eq!(error.api_error, None);
+        assert!(error.is_retryable());
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[cfg(any(
+        feature = "default-tls",
+        feature = "native-tls",
+        feature = "rustls-tls"
+    ))]
+    #[tokio::test]
+    async fn from_error_detects_tls_failures_by_type() {
+        // Um servidor HTTP simples não completa o handshake TLS. Este teste também
+        // garante que as versões de native-tls/rustls do Cargo.toml são as do `reqwest`.
+        let server = httpmock::MockServer::start_async().await;
+        let url = format!("https://{}/ssl", server.address());
+
+        let error = reqwest::get(&url).await.unwrap_err();
+
+        assert_eq!(Error::from_error(error).error, Errored::Tls);
     }
 }
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{error::Error, spec::BRASIL_API_URL};
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::Error,
+};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Brand {
@@ -50,14 +53,22 @@ impl VehicleType {
     }
 }
 
-pub struct FipeService {
-    base_url: String,
+pub struct FipeService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl FipeService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
+        }
+    }
+}
+
+impl<T: Transport> FipeService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
@@ -65,7 +76,7 @@ impl FipeService {
         &self,
         vehicle_type: VehicleType,
         reference_table: Option<i64>,
-    ) -> Result<reqwest::Response, Error> {
+    ) -> Result<Response, Error> {
         let vehicle_type = vehicle_type.to_string();
 
         let reference_table = match reference_table {
@@ -73,45 +84,73 @@ impl FipeService {
             None => "".to_string(),
         };
 
-        let url = format!(
-            "{}/api/fipe/marcas/v1/{}?{}",
-            self.base_url, vehicle_type, reference_table
-        );
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+        self.client
+            .get(
+                "fipe.get_brands",
+                &format!("/api/fipe/marcas/v1/{vehicle_type}?{reference_table}"),
+            )
+            .await
     }
 
     async fn get_vehicle_request(
         &self,
         fipe_code: &str,
         reference_table: Option<i64>,
-    ) -> Result<reqwest::Response, Error> {
+    ) -> Result<Response, Error> {
         let reference_table = match reference_table {
             Some(reference_table) => format!("tabela_referencia={reference_table}"),
             None => "".to_string(),
         };
 
-        let url = format!(
-            "{}/api/fipe/preco/v1/{}?{}",
-            self.base_url, fipe_code, reference_table
-        );
+        self.client
+            .get(
+                "fipe.get_vehicles",
+                &format!("/api/fipe/preco/v1/{fipe_code}?{reference_table}"),
+            )
+            .await
+    }
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+    async fn get_reference_tables_request(&self) -> Result<Response, Error> {
+        self.client
+            .get("fipe.get_reference_tables", "/api/fipe/tabelas/v1/")
+            .await
     }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Lista as marcas de veículos utilizando este cliente. Veja [`get_brands`].
+    pub async fn get_fipe_brands(
+        &self,
+        vehicle_type: VehicleType,
+        reference_table: Option<i64>,
+    ) -> Result<Vec<Brand>, Error> {
+        let response = FipeService::with_client(self)
+            .get_brands_request(vehicle_type, reference_table)
+            .await?;
 
-    async fn get_reference_tables_request(&self) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/fipe/tabelas/v1/", self.base_url);
+        response.json()
+    }
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+    /// Consulta o preço de um veículo utilizando este cliente. Veja [`get_vehicles`].
+    pub async fn get_fipe_vehicles(
+        &self,
+        fipe_code: &str,
+        reference_table: Option<i64>,
+    ) -> Result<Vec<Vehicle>, Error> {
+        let response = FipeService::with_client(self)
+            .get_vehicle_request(fipe_code, reference_table)
+            .await?;
+
+        response.json()
+    }
+
+    /// Lista as tabelas de referência utilizando este cliente. Veja [`get_reference_tables`].
+    pub async fn get_fipe_reference_tables(&self) -> Result<Vec<ReferenceTable>, Error> {
+        let response = FipeService::with_client(self)
+            .get_reference_tables_request()
+            .await?;
+
+        response.json()
     }
 }
 
@@ -139,16 +178,9 @@ pub async fn get_brands(
     vehicle_type: VehicleType,
     reference_table: Option<i64>,
 ) -> Result<Vec<Brand>, Error> {
-    let fipe_service = FipeService::new(BRASIL_API_URL);
-
-    let response = fipe_service
-        .get_brands_request(vehicle_type, reference_table)
-        .await?;
-
-    let body = response.text().await.unwrap();
-    let brands: Vec<Brand> = serde_json::from_str(&body).unwrap();
-
-    Ok(brands)
+    default_client()
+        .get_fipe_brands(vehicle_type, reference_table)
+        .await
 }
 
 /// #### `get_vehicles(fipe_code: &str, reference_table: Option<i64>)`
@@ -174,16 +206,9 @@ pub async fn get_vehicles(
     fipe_code: &str,
     reference_table: Option<i64>,
 ) -> Result<Vec<Vehicle>, Error> {
-    let fipe_service = FipeService::new(BRASIL_API_URL);
-
-    let response = fipe_service
-        .get_vehicle_request(fipe_code, reference_table)
-        .await?;
-
-    let body = response.text().await.unwrap();
-    let vehicle: Vec<Vehicle> = serde_json::from_str(&body).unwrap();
-
-    Ok(vehicle)
+    default_client()
+        .get_fipe_vehicles(fipe_code, reference_table)
+        .await
 }
 
 /// #### `get_reference_tables()`
@@ -202,14 +227,7 @@ pub async fn get_vehicles(
 /// }
 /// ```
 pub async fn get_reference_tables() -> Result<Vec<ReferenceTable>, Error> {
-    let fipe_service = FipeService::new(BRASIL_API_URL);
-
-    let response = fipe_service.get_reference_tables_request().await?;
-
-    let body = response.text().await.unwrap();
-    let reference_tables: Vec<ReferenceTable> = serde_json::from_str(&body).unwrap();
-
-    Ok(reference_tables)
+    default_client().get_fipe_reference_tables().await
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use crate::{error::*, spec::BRASIL_API_URL};
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::*,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -10,23 +13,57 @@ pub struct Holiday {
     pub full_name: Option<String>,
 }
 
-pub struct HolidayService {
-    base_url: String,
+pub struct HolidayService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl HolidayService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
+
+impl<T: Transport> HolidayService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
+        }
+    }
+
+    async fn get_holiday_request(&self, year: &str) -> Result<Response, Error> {
+        self.client
+            .get("holidays.list", &format!("/api/feriados/v1/{year}"))
+            .await
+    }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Lista os feriados nacionais de um ano utilizando este cliente. Veja [`get_holidays`].
+    pub async fn get_holidays(&self, year: &str) -> Result<Vec<Holiday>, Error> {
+        let response = HolidayService::with_client(self)
+            .get_holiday_request(year)
+            .await?;
 
-    async fn get_holiday_request(&self, year: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/feriados/v1/{}", self.base_url, year);
+        response.json()
+    }
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+    /// Retorna o feriado de uma data utilizando este cliente. Veja [`get_holiday`].
+    pub async fn get_holiday(&self, year: &str, month: &str, day: &str) -> Result<Holiday, Error> {
+        let holidays = self.get_holidays(year).await?;
+
+        let holiday_position = holidays
+            .iter()
+            .position(|holiday| holiday.date == format!("{year}-{month}-{day}"));
+
+        match holiday_position {
+            Some(position) => Ok(holidays.get(position).unwrap().clone()),
+            None => Err(Error::new(
+                String::from("holiday not found"),
+                Errored::NotFound,
+                Some(404),
+            )),
         }
     }
 }
@@ -51,14 +88,7 @@ impl HolidayService {
 /// }
 /// ```
 pub async fn get_holidays(year: &str) -> Result<Vec<Holiday>, Error> {
-    let holiday_service = HolidayService::new(BRASIL_API_URL);
-
-    let response = holiday_service.get_holiday_request(year).await?;
-
-    let body = response.text().await.unwrap();
-    let holidays: Vec<Holiday> = serde_json::from_str(&body).unwrap();
-
-    Ok(holidays)
+    default_client().get_holidays(year).await
 }
 
 /// #### `get_holiday(year: &str, month: &str, day: &str)`
@@ -83,20 +113,7 @@ pub async fn get_holidays(year: &str) -> Result<Vec<Holiday>, Error> {
 /// }
 /// ```
 pub async fn get_holiday(year: &str, month: &str, day: &str) -> Result<Holiday, Error> {
-    let holidays = get_holidays(year).await?;
-
-    let holiday_position = holidays
-        .iter()
-        .position(|holiday| holiday.date == format!("{year}-{month}-{day}"));
-
-    match holiday_position {
-        Some(position) => Ok(holidays.get(position).unwrap().clone()),
-        None => Err(Error::new(
-            String::from("holiday not found"),
-            Errored::NotFound,
-            Some(404),
-        )),
-    }
+    default_client().get_holiday(year, month, day).await
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use crate::{error::Error, spec::BRASIL_API_URL};
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::Error,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
@@ -38,14 +41,22 @@ impl MunicipalitiesProvider {
     }
 }
 
-pub struct IbgeService {
-    base_url: String,
+pub struct IbgeService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl IbgeService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
+        }
+    }
+}
+
+impl<T: Transport> IbgeService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
@@ -53,7 +64,7 @@ impl IbgeService {
         &self,
         uf: &str,
         providers: Option<Vec<MunicipalitiesProvider>>,
-    ) -> Result<reqwest::Response, Error> {
+    ) -> Result<Response, Error> {
         let providers = match providers {
             Some(providers) => providers
                 .iter()
@@ -63,33 +74,55 @@ impl IbgeService {
             None => "".to_string(),
         };
 
-        let url = format!(
-            "{}/api/ibge/municipios/v1/{}?providers={}",
-            self.base_url, uf, providers
-        );
+        self.client
+            .get(
+                "ibge.get_municipalities",
+                &format!("/api/ibge/municipios/v1/{uf}?providers={providers}"),
+            )
+            .await
+    }
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+    async fn get_all_states_request(&self) -> Result<Response, Error> {
+        self.client.get("ibge.list_states", "/api/ibge/uf/v1").await
     }
 
-    async fn get_all_states_request(&self) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/ibge/uf/v1", self.base_url);
+    async fn get_state_request(&self, code: &str) -> Result<Response, Error> {
+        self.client
+            .get("ibge.get_state", &format!("/api/ibge/uf/v1/{code}"))
+            .await
+    }
+}
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+impl<T: Transport> BrasilApiClient<T> {
+    /// Retorna os municípios de um estado utilizando este cliente. Veja [`get_municipalities`].
+    pub async fn get_municipalities(
+        &self,
+        uf: &str,
+        providers: Option<Vec<MunicipalitiesProvider>>,
+    ) -> Result<Vec<Municipality>, Error> {
+        let response = IbgeService::with_client(self)
+            .get_municipalities_request(uf, providers)
+            .await?;
+
+        response.json()
     }
 
-    async fn get_state_request(&self, code: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/ibge/uf/v1/{}", self.base_url, code);
+    /// Retorna todos os estados utilizando este cliente. Veja [`get_all_states`].
+    pub async fn get_all_states(&self) -> Result<Vec<State>, Error> {
+        let response = IbgeService::with_client(self)
+            .get_all_states_request()
+            .await?;
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+        response.json()
+    }
+
+    /// Busca um estado pela sigla ou código utilizando este cliente. Veja [`get_state`].
+    pub async fn get_state(&self, code: &str) -> Result<State, Error> {
+        let response = IbgeService::with_client(self)
+            .get_state_request(code)
+            .await?;
+
+        response.json()
     }
 }
 
@@ -116,16 +149,7 @@ pub async fn get_municipalities(
     uf: &str,
     providers: Option<Vec<MunicipalitiesProvider>>,
 ) -> Result<Vec<Municipality>, Error> {
-    let ibge_service = IbgeService::new(BRASIL_API_URL);
-
-    let response = ibge_service
-        .get_municipalities_request(uf, providers)
-        .await?;
-
-    let body = response.text().await.unwrap();
-    let municipalities: Vec<Municipality> = serde_json::from_str(&body).unwrap();
-
-    Ok(municipalities)
+    default_client().get_municipalities(uf, providers).await
 }
 
 /// #### `get_all_states()`
@@ -144,14 +168,7 @@ pub async fn get_municipalities(
 /// }
 /// ```
 pub async fn get_all_states() -> Result<Vec<State>, Error> {
-    let ibge_service = IbgeService::new(BRASIL_API_URL);
-
-    let response = ibge_service.get_all_states_request().await?;
-
-    let body = response.text().await.unwrap();
-    let states: Vec<State> = serde_json::from_str(&body).unwrap();
-
-    Ok(states)
+    default_client().get_all_states().await
 }
 
 /// #### `get_state(code: &str)`
@@ -173,14 +190,7 @@ pub async fn get_all_states() -> Result<Vec<State>, Error> {
 /// }
 /// ```
 pub async fn get_state(code: &str) -> Result<State, Error> {
-    let ibge_service = IbgeService::new(BRASIL_API_URL);
-
-    let response = ibge_service.get_state_request(code).await?;
-
-    let body = response.text().await.unwrap();
-    let state: State = serde_json::from_str(&body).unwrap();
-
-    Ok(state)
+    default_client().get_state(code).await
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use crate::{error::Error, spec::BRASIL_API_URL};
+use crate::{
+    client::{batch, default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::Error,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -56,26 +59,31 @@ pub enum Provider {
     GoogleBooks,
 }
 
-pub struct IsbnService {
-    base_url: String,
+pub struct IsbnService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl IsbnService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_isbn_request(&self, isbn_code: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/isbn/v1/{}", self.base_url, isbn_code);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> IsbnService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
+    async fn get_isbn_request(&self, isbn_code: &str) -> Result<Response, Error> {
+        self.client
+            .get("isbn.get", &format!("/api/isbn/v1/{isbn_code}"))
+            .await
+    }
+
     async fn validate_isbn(&self, isbn_code: &str) -> Result<bool, Error> {
         let response = self.get_isbn_request(isbn_code).await;
 
@@ -89,6 +97,24 @@ impl IsbnService {
     }
 }
 
+impl<T: Transport> BrasilApiClient<T> {
+    /// Busca por ISBN utilizando este cliente. Veja [`get_isbn`].
+    pub async fn get_isbn(&self, isbn_code: &str) -> Result<Isbn, Error> {
+        let response = IsbnService::with_client(self)
+            .get_isbn_request(isbn_code)
+            .await?;
+
+        response.json()
+    }
+
+    /// Valida um ISBN utilizando este cliente. Veja [`validate`].
+    pub async fn validate_isbn(&self, isbn_code: &str) -> Result<bool, Error> {
+        IsbnService::with_client(self)
+            .validate_isbn(isbn_code)
+            .await
+    }
+}
+
 /// #### `get_isbn(isbn_code: &str)`
 /// Busca por **ISBN** nos provedores:
 /// * CBL;
@@ -112,14 +138,7 @@ impl IsbnService {
 /// }
 /// ```
 pub async fn get_isbn(isbn_code: &str) -> Result<Isbn, Error> {
-    let isbn_service = IsbnService::new(BRASIL_API_URL);
-
-    let response = isbn_service.get_isbn_request(isbn_code).await?;
-
-    let body = response.text().await.unwrap();
-    let isbn: Isbn = serde_json::from_str(&body).unwrap();
-
-    Ok(isbn)
+    default_client().get_isbn(isbn_code).await
 }
 
 /// #### `validate(isbn_code: &str)`
@@ -140,9 +159,15 @@ pub async fn get_isbn(isbn_code: &str) -> Result<Isbn, Error> {
 ///   let is_valid = isbn::validate("8535914846").await.unwrap();  
 /// }
 pub async fn validate(isbn_code: &str) -> Result<bool, Error> {
-    IsbnService::new(BRASIL_API_URL)
-        .validate_isbn(isbn_code)
-        .await
+    default_client().validate_isbn(isbn_code).await
+}
+
+batch::batch_lookups! {
+    module: isbn,
+    item: Isbn,
+    noun: "ISBN",
+    client: get_isbns, stream_isbns => get_isbn,
+    example: r#"["9788545702870", "9788575422397"]"#,
 }
 
 #[cfg(test)]
@@ -4,6 +4,15 @@
 //!
 //! ## Exemplos
 //! Atualmente o brasilapi-rs utiliza `async/await` para fazer as requisições, então você precisa de um runtime async para rodar o código, como o [tokio](https://crates.io/crates/tokio).
+//!
+//! A biblioteca não depende de um runtime específico: retry, rate limit e cache funcionam em qualquer executor.
+//! O transporte padrão ([`ReqwestTransport`](client/struct.ReqwestTransport.html)) usa o `reqwest`, que precisa
+//! de um runtime tokio (basta um runtime de uma única thread). A biblioteca não inclui um transporte para outros
+//! runtimes: em async-std, smol ou outros executores é preciso implementar um
+//! [`Transport`](client/trait.Transport.html) próprio, como no exemplo da documentação do trait.
+//!
+//! No `wasm32-unknown-unknown` as requisições usam o `fetch` do navegador, sem tokio. Nesse alvo o cache em disco e
+//! o módulo `blocking` não estão disponíveis.
 //! ```rust
 //! use brasilapi::cep;
 //!
@@ -23,10 +32,34 @@
 //! }
 //! ```
 //!
+//! ### Cliente compartilhado
+//! Para reaproveitar conexões e configurar timeouts, `User-Agent`, proxies ou headers padrão,
+//! utilize o [`BrasilApiClient`](client/struct.BrasilApiClient.html):
+//! ```rust
+//! use std::time::Duration;
+//! use brasilapi::client::BrasilApiClient;
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let client = BrasilApiClient::builder()
+//!         .timeout(Duration::from_secs(10))
+//!         .build()
+//!         .unwrap();
+//!
+//!     let cep = client.get_cep("01001000").await.unwrap();
+//!     let banks = client.get_all_banks().await.unwrap();
+//! }
+//! ```
+//!
 //! ## Módulos
-//! A biblioteca é dividida em módulos, cada um com sua responsabilidade:
+//! A biblioteca é dividida em módulos, cada um com sua responsabilidade. Cada módulo de consulta é habilitado por uma
+//! feature com o mesmo nome; a feature `full`, padrão, habilita todos eles e o cache em disco (`disk-cache`).
+//! O backend TLS é escolhido pelas features `default-tls` (padrão), `native-tls` ou `rustls-tls`, e a feature
+//! `tracing` emite um span para cada requisição:
 //! * [Bank](bank/index.html) - Informações sobre sistema bancário brasileiro.
+//! * [Blocking](blocking/index.html) - Versão síncrona de todos os módulos (feature `blocking`)
 //! * [Cep](cep/index.html) - Informações referentes a CEPs
+//! * [Client](client/index.html) - Cliente HTTP compartilhado e configurável (habilitado por qualquer módulo de consulta)
 //! * [Cnpj](cnpj/index.html) - Busca dados de empresas por CNPJ
 //! * [Corretoras](corretoras/index.html) - Informações referentes a Corretoras ativas listadas na CVM
 //! * [Ddd](ddd/index.html) - Informações relacionadas a DDDs
@@ -36,19 +69,43 @@
 //! * [Isbn](isbn/index.html) - Informações referentes a ISBNs
 //! * [Pix](pix/index.html) - Informações referentes ao PIX
 //! * [Registrobr](registrobr/index.html) - Avalia um dominio no registro.br
+//! * [Testing](testing/index.html) - API simulada em memória para testes (feature `testing`)
 //! * [Error](error/index.html) - Estrutura de erros da biblioteca
+
+#[cfg(feature = "bank")]
 pub mod bank;
+#[cfg(all(
+    feature = "blocking",
+    feature = "__client",
+    not(target_arch = "wasm32")
+))]
+pub mod blocking;
+#[cfg(feature = "cep")]
 pub mod cep;
+#[cfg(feature = "__client")]
+pub mod client;
+#[cfg(feature = "cnpj")]
 pub mod cnpj;
+#[cfg(feature = "corretoras")]
 pub mod corretoras;
+#[cfg(feature = "ddd")]
 pub mod ddd;
 pub mod error;
+#[cfg(feature = "fipe")]
 pub mod fipe;
+#[cfg(feature = "holidays")]
 pub mod holidays;
+#[cfg(feature = "ibge")]
 pub mod ibge;
+#[cfg(feature = "isbn")]
 pub mod isbn;
+#[cfg(feature = "pix")]
 pub mod pix;
+#[cfg(feature = "registrobr")]
 pub mod registrobr;
+#[cfg(feature = "taxas")]
 pub mod taxas;
+#[cfg(all(feature = "testing", feature = "__client"))]
+pub mod testing;
 
 pub mod spec;
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::error::Error;
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::Error,
+};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 pub struct Participant {
@@ -12,25 +15,41 @@ pub struct Participant {
     pub inicio_operacao: String,
 }
 
-pub struct PIXService {
-    base_url: String,
+pub struct PIXService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl PIXService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_participant_request(&self) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/pix/v1/participants", self.base_url);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> PIXService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
+
+    async fn get_participant_request(&self) -> Result<Response, Error> {
+        self.client
+            .get("pix.list_participants", "/api/pix/v1/participants")
+            .await
+    }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Retorna os participantes do PIX utilizando este cliente. Veja [`get_participants`].
+    pub async fn get_pix_participants(&self) -> Result<Vec<Participant>, Error> {
+        let response = PIXService::with_client(self)
+            .get_participant_request()
+            .await?;
+
+        response.json()
+    }
 }
 
 /// #### `get_participants()`
@@ -49,14 +68,7 @@ impl PIXService {
 /// }
 /// ```
 pub async fn get_participants() -> Result<Vec<Participant>, Error> {
-    let pix_service = PIXService::new("https://brasilapi.com.br");
-
-    let response = pix_service.get_participant_request().await?;
-
-    let body = response.text().await.unwrap();
-    let participants: Vec<Participant> = serde_json::from_str(&body).unwrap();
-
-    Ok(participants)
+    default_client().get_pix_participants().await
 }
 
 #[cfg(test)]
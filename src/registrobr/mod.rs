@@ -1,4 +1,7 @@
-use crate::{error::*, spec::BRASIL_API_URL};
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::*,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -15,25 +18,44 @@ pub struct Domain {
     pub reasons: Option<Vec<String>>,
 }
 
-pub struct RegistroBrService {
-    base_url: String,
+pub struct RegistroBrService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl RegistroBrService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_domain_by_name(&self, name: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/registrobr/v1/{}", self.base_url, name);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> RegistroBrService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
+
+    async fn get_domain_by_name(&self, name: &str) -> Result<Response, Error> {
+        self.client
+            .get(
+                "registrobr.get_domain",
+                &format!("/api/registrobr/v1/{name}"),
+            )
+            .await
+    }
+}
+
+impl<T: Transport> BrasilApiClient<T> {
+    /// Retorna informações sobre um domínio utilizando este cliente. Veja [`get_domain_by_name`].
+    pub async fn get_domain_by_name(&self, name: &str) -> Result<Domain, Error> {
+        let response = RegistroBrService::with_client(self)
+            .get_domain_by_name(name)
+            .await?;
+
+        response.json()
+    }
 }
 
 /// #### `get_domain_by_name(name: &str)`
@@ -55,14 +77,7 @@ impl RegistroBrService {
 /// }
 /// ```
 pub async fn get_domain_by_name(name: &str) -> Result<Domain, Error> {
-    let registro_br_service = RegistroBrService::new(BRASIL_API_URL);
-
-    let response = registro_br_service.get_domain_by_name(name).await?;
-
-    let body = response.text().await.unwrap();
-    let domain: Domain = serde_json::from_str(&body).unwrap();
-
-    Ok(domain)
+    default_client().get_domain_by_name(name).await
 }
 
 #[cfg(test)]
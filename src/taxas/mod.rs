@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 
-use crate::{error::Error, spec::BRASIL_API_URL};
+use crate::{
+    client::{default_client, BrasilApiClient, ReqwestTransport, Response, Transport},
+    error::Error,
+};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Taxa {
@@ -8,33 +11,51 @@ pub struct Taxa {
     pub valor: f64,
 }
 
-pub struct TaxasService {
-    base_url: String,
+pub struct TaxasService<T = ReqwestTransport> {
+    client: BrasilApiClient<T>,
 }
 
 impl TaxasService {
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.to_string(),
+            client: BrasilApiClient::with_base_url(base_url),
         }
     }
+}
 
-    async fn get_taxa_request(&self, sigla: &str) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/taxas/v1/{}", self.base_url, sigla);
-
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
+impl<T: Transport> TaxasService<T> {
+    pub fn with_client(client: &BrasilApiClient<T>) -> Self {
+        Self {
+            client: client.clone(),
         }
     }
 
-    async fn list_taxas_request(&self) -> Result<reqwest::Response, Error> {
-        let url = format!("{}/api/taxas/v1", self.base_url);
+    async fn get_taxa_request(&self, sigla: &str) -> Result<Response, Error> {
+        self.client
+            .get("taxas.get", &format!("/api/taxas/v1/{sigla}"))
+            .await
+    }
+
+    async fn list_taxas_request(&self) -> Result<Response, Error> {
+        self.client.get("taxas.list", "/api/taxas/v1").await
+    }
+}
 
-        match reqwest::get(&url).await {
-            Ok(response) => Error::from_response(response).await,
-            Err(e) => Err(Error::from_error(e)),
-        }
+impl<T: Transport> BrasilApiClient<T> {
+    /// Busca uma taxa utilizando este cliente. Veja [`get_taxa`].
+    pub async fn get_taxa(&self, sigla: &str) -> Result<Taxa, Error> {
+        let response = TaxasService::with_client(self)
+            .get_taxa_request(sigla)
+            .await?;
+
+        response.json()
+    }
+
+    /// Lista todas as taxas utilizando este cliente. Veja [`list_taxas`].
+    pub async fn list_taxas(&self) -> Result<Vec<Taxa>, Error> {
+        let response = TaxasService::with_client(self).list_taxas_request().await?;
+
+        response.json()
     }
 }
 
@@ -55,13 +76,7 @@ impl TaxasService {
 /// }
 /// ```
 pub async fn get_taxa(sigla: &str) -> Result<Taxa, Error> {
-    let service = TaxasService::new(BRASIL_API_URL);
-    let response = service.get_taxa_request(sigla).await?;
-
-    let body = response.text().await.unwrap();
-    let taxa: Taxa = serde_json::from_str(&body).unwrap();
-
-    Ok(taxa)
+    default_client().get_taxa(sigla).await
 }
 
 /// #### `list_taxas()`
@@ -77,13 +92,7 @@ pub async fn get_taxa(sigla: &str) -> Result<Taxa, Error> {
 /// }
 /// ```
 pub async fn list_taxas() -> Result<Vec<Taxa>, Error> {
-    let service = TaxasService::new(BRASIL_API_URL);
-    let response = service.list_taxas_request().await?;
-
-    let body = response.text().await.unwrap();
-    let taxas: Vec<Taxa> = serde_json::from_str(&body).unwrap();
-
-    Ok(taxas)
+    default_client().list_taxas().await
 }
 
 #[cfg(test)]
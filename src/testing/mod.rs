@@ -0,0 +1,256 @@
+//! Utilitários para testar integrações com a BrasilAPI sem acesso à rede.
+//!
+//! Disponível com a feature `testing`. O [`MockBrasilApi`] cria um [`InMemoryTransport`]
+//! com respostas reais de todos os endpoints da biblioteca (veja [`fixtures`]) e permite
+//! simular cenários de erro com [`Scenario`].
+//!
+//! # Exemplo
+//! ```rust
+//! use std::time::Duration;
+//! use brasilapi::testing::{MockBrasilApi, Scenario};
+//!
+//! #[tokio::main]
+//! async fn main() {
+//!     let api = MockBrasilApi::new().scenario("/api/cnpj/v1/00000000000191", Scenario::InternalServerError);
+//!     let client = api.client();
+//!
+//!     let cep = client.get_cep("01001000").await.unwrap();
+//!     assert_eq!(cep.state, "SP");
+//!
+//!     let error = client.get_cnpj("00000000000191").await.unwrap_err();
+//!     assert_eq!(error.code, Some(500));
+//! }
+//! ```
+use std::time::Duration;
+
+use crate::client::{BrasilApiClient, HttpRequest, InMemoryTransport};
+
+/// Respostas da BrasilAPI usadas pelo [`MockBrasilApi`], com as rotas que as retornam.
+pub mod fixtures {
+    /// `/api/cep/v2/01001000`
+    pub const CEP: &str = include_str!("fixtures/cep.json");
+    /// `/api/cnpj/v1/00000000000191`
+    pub const CNPJ: &str = include_str!("fixtures/cnpj.json");
+    /// `/api/banks/v1`
+    pub const BANKS: &str = include_str!("fixtures/banks.json");
+    /// `/api/banks/v1/1`
+    pub const BANK: &str = include_str!("fixtures/bank.json");
+    /// `/api/fipe/marcas/v1/carros?`
+    pub const FIPE_BRANDS: &str = include_str!("fixtures/fipe_brands.json");
+    /// `/api/fipe/preco/v1/001004-9?`
+    pub const FIPE_VEHICLES: &str = include_str!("fixtures/fipe_vehicles.json");
+    /// `/api/fipe/tabelas/v1/`
+    pub const FIPE_REFERENCE_TABLES: &str = include_str!("fixtures/fipe_reference_tables.json");
+    /// `/api/feriados/v1/2022`
+    pub const HOLIDAYS: &str = include_str!("fixtures/holidays.json");
+    /// `/api/ibge/municipios/v1/AC?providers=`
+    pub const IBGE_MUNICIPALITIES: &str = include_str!("fixtures/ibge_municipalities.json");
+    /// `/api/ibge/uf/v1`
+    pub const IBGE_STATES: &str = include_str!("fixtures/ibge_states.json");
+    /// `/api/ibge/uf/v1/SP`
+    pub const IBGE_STATE: &str = include_str!("fixtures/ibge_state.json");
+    /// `/api/isbn/v1/9788545702870`
+    pub const ISBN: &str = include_str!("fixtures/isbn.json");
+    /// `/api/ddd/v1/61`
+    pub const DDD: &str = include_str!("fixtures/ddd.json");
+    /// `/api/taxas/v1`
+    pub const TAXAS: &str = include_str!("fixtures/taxas.json");
+    /// `/api/taxas/v1/SELIC`
+    pub const TAXA: &str = include_str!("fixtures/taxa.json");
+    /// `/api/cvm/corretoras/v1`
+    pub const CORRETORAS: &str = include_str!("fixtures/corretoras.json");
+    /// `/api/cvm/corretoras/v1/02332886000104`
+    pub const CORRETORA: &str = include_str!("fixtures/corretora.json");
+    /// `/api/pix/v1/participants`
+    pub const PIX_PARTICIPANTS: &str = include_str!("fixtures/pix_participants.json");
+    /// `/api/registrobr/v1/brasilapi.com.br`
+    pub const REGISTROBR: &str = include_str!("fixtures/registrobr.json");
+
+    /// Rotas cadastradas por padrão no [`MockBrasilApi`](super::MockBrasilApi).
+    pub const ROUTES: &[(&str, &str)] = &[
+        ("/api/cep/v2/01001000", CEP),
+        ("/api/cnpj/v1/00000000000191", CNPJ),
+        ("/api/banks/v1", BANKS),
+        ("/api/banks/v1/1", BANK),
+        ("/api/fipe/marcas/v1/carros?", FIPE_BRANDS),
+        ("/api/fipe/preco/v1/001004-9?", FIPE_VEHICLES),
+        ("/api/fipe/tabelas/v1/", FIPE_REFERENCE_TABLES),
+        ("/api/feriados/v1/2022", HOLIDAYS),
+        ("/api/ibge/municipios/v1/AC?providers=", IBGE_MUNICIPALITIES),
+        ("/api/ibge/uf/v1", IBGE_STATES),
+        ("/api/ibge/uf/v1/SP", IBGE_STATE),
+        ("/api/isbn/v1/9788545702870", ISBN),
+        ("/api/ddd/v1/61", DDD),
+        ("/api/taxas/v1", TAXAS),
+        ("/api/taxas/v1/SELIC", TAXA),
+        ("/api/cvm/corretoras/v1", CORRETORAS),
+        ("/api/cvm/corretoras/v1/02332886000104", CORRETORA),
+        ("/api/pix/v1/participants", PIX_PARTICIPANTS),
+        ("/api/registrobr/v1/brasilapi.com.br", REGISTROBR),
+    ];
+}
+
+/// Cenário simulado para uma rota do [`MockBrasilApi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Scenario {
+    /// Responde 404, como a API faz para recursos inexistentes.
+    NotFound,
+    /// Responde 500.
+    InternalServerError,
+    /// Responde 200 com um corpo que não é JSON válido.
+    MalformedJson,
+    /// Mantém a resposta atual da rota, mas só responde depois do intervalo.
+    Slow(Duration),
+}
+
+/// BrasilAPI simulada em memória.
+///
+/// Todas as rotas de [`fixtures::ROUTES`] já vêm cadastradas. Clones compartilham as
+/// mesmas rotas e o mesmo registro de requisições.
+#[derive(Debug, Clone)]
+pub struct MockBrasilApi {
+    transport: InMemoryTransport,
+}
+
+impl Default for MockBrasilApi {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockBrasilApi {
+    /// Cria a API simulada com todas as [`fixtures`].
+    pub fn new() -> Self {
+        fixtures::ROUTES
+            .iter()
+            .fold(Self::empty(), |api, (path, body)| {
+                api.route(path, 200, body)
+            })
+    }
+
+    /// Cria a API simulada sem nenhuma rota; todas as requisições respondem 404.
+    pub fn empty() -> Self {
+        Self {
+            transport: InMemoryTransport::new(),
+        }
+    }
+
+    /// Responde `body` com o status `status` para o caminho `path`.
+    pub fn route(self, path: &str, status: u16, body: &str) -> Self {
+        Self {
+            transport: self.transport.route(path, status, body),
+        }
+    }
+
+    /// Aplica um [`Scenario`] ao caminho `path`.
+    pub fn scenario(self, path: &str, scenario: Scenario) -> Self {
+        let transport = match scenario {
+            Scenario::NotFound => self.transport.route(
+                path,
+                404,
+                r#"{"name": "NotFoundError", "message": "Recurso não encontrado", "type": "not_found"}"#,
+            ),
+            Scenario::InternalServerError => self.transport.route(
+                path,
+                500,
+                r#"{"name": "InternalError", "message": "Erro interno do servidor", "type": "internal_error"}"#,
+            ),
+            Scenario::MalformedJson => self.transport.route(path, 200, r#"{"malformed": "#),
+            Scenario::Slow(delay) => self.transport.delay(path, delay),
+        };
+
+        Self { transport }
+    }
+
+    /// Cliente que envia as requisições para esta API simulada.
+    pub fn client(&self) -> BrasilApiClient<InMemoryTransport> {
+        BrasilApiClient::with_transport(self.transport.clone())
+    }
+
+    /// Transporte em memória, para montar um cliente com
+    /// [`build_with_transport`](crate::client::BrasilApiClientBuilder::build_with_transport).
+    pub fn transport(&self) -> InMemoryTransport {
+        self.transport.clone()
+    }
+
+    /// Requisições recebidas até o momento, em ordem.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.transport.requests()
+    }
+}
+
+#[cfg(test)]
+mod testing_tests {
+
+    #[cfg(feature = "full")]
+    #[tokio::test]
+    async fn fixtures_decode_for_every_endpoint() {
+        use super::MockBrasilApi;
+        use crate::fipe::VehicleType;
+
+        let client = MockBrasilApi::new().client();
+
+        assert_eq!(client.get_cep("01001000").await.unwrap().city, "São Paulo");
+        assert!(client.get_cnpj("00000000000191").await.is_ok());
+        assert_eq!(client.get_all_banks().await.unwrap().len(), 3);
+        assert_eq!(client.get_bank(1).await.unwrap().code, Some(1));
+        assert!(client.get_fipe_brands(VehicleType::Car, None).await.is_ok());
+        assert!(client.get_fipe_vehicles("001004-9", None).await.is_ok());
+        assert!(client.get_fipe_reference_tables().await.is_ok());
+        assert_eq!(
+            client.get_holiday("2022", "09", "07").await.unwrap().name,
+            "Independência do Brasil"
+        );
+        assert!(client.get_municipalities("AC", None).await.is_ok());
+        assert_eq!(client.get_all_states().await.unwrap().len(), 3);
+        assert_eq!(client.get_state("SP").await.unwrap().sigla, "SP");
+        assert_eq!(
+            client.get_isbn("9788545702870").await.unwrap().title,
+            "Akira"
+        );
+        assert_eq!(client.get_ddd("61").await.unwrap().state, "DF");
+        assert_eq!(client.list_taxas().await.unwrap().len(), 3);
+        assert_eq!(client.get_taxa("SELIC").await.unwrap().nome, "Selic");
+        assert_eq!(client.get_corretoras().await.unwrap().len(), 1);
+        assert!(client.get_corretora("02332886000104").await.is_ok());
+        assert_eq!(client.get_pix_participants().await.unwrap().len(), 2);
+        assert_eq!(
+            client
+                .get_domain_by_name("brasilapi.com.br")
+                .await
+                .unwrap()
+                .status,
+            "REGISTERED"
+        );
+    }
+
+    #[cfg(all(feature = "bank", feature = "cep", feature = "ddd", feature = "taxas"))]
+    #[tokio::test]
+    async fn scenarios_simulate_errors() {
+        use super::{Duration, MockBrasilApi, Scenario};
+        use crate::error::Errored;
+
+        let api = MockBrasilApi::new()
+            .scenario("/api/cep/v2/01001000", Scenario::NotFound)
+            .scenario("/api/banks/v1", Scenario::InternalServerError)
+            .scenario("/api/ddd/v1/61", Scenario::MalformedJson)
+            .scenario("/api/taxas/v1", Scenario::Slow(Duration::from_millis(50)));
+        let client = api.client();
+
+        assert!(!client.validate_cep("01001000").await.unwrap());
+        assert_eq!(
+            client.get_all_banks().await.unwrap_err().error,
+            Errored::InternalServerError
+        );
+        assert!(matches!(
+            client.get_ddd("61").await.unwrap_err().error,
+            Errored::Decode(_)
+        ));
+
+        let started = std::time::Instant::now();
+        assert_eq!(client.list_taxas().await.unwrap().len(), 3);
+        assert!(started.elapsed() >= Duration::from_millis(50));
+
+        assert_eq!(api.requests().len(), 4);
+    }
+}
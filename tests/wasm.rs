@@ -0,0 +1,62 @@
+//! Testes executados no navegador, com `wasm-pack test --headless --chrome -- --test wasm`.
+#![cfg(target_arch = "wasm32")]
+
+use std::time::Duration;
+
+use brasilapi::{
+    client::{
+        BrasilApiClient, Cache, HttpResponse, InMemoryTransport, Quota, RateLimiter, RetryPolicy,
+    },
+    error::Errored,
+};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+async fn retry_and_rate_limit_use_browser_timers() {
+    let transport = InMemoryTransport::new().respond(
+        "/api/banks/v1",
+        HttpResponse::new(503, "Service Unavailable"),
+    );
+
+    let client = BrasilApiClient::builder()
+        .retry(
+            RetryPolicy::new()
+                .max_attempts(2)
+                .backoff(Duration::from_millis(5), Duration::from_millis(5)),
+        )
+        .rate_limit(RateLimiter::new(Quota::per_second(100).burst(1)))
+        .build_with_transport(transport.clone());
+
+    let error = client.get_all_banks().await.unwrap_err();
+
+    assert_eq!(error.error, Errored::ServiceUnavailable);
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[wasm_bindgen_test]
+async fn cache_uses_browser_clock() {
+    let transport = InMemoryTransport::new().route(
+        "/api/ddd/v1/61",
+        200,
+        r#"{"state": "DF", "cities": ["BRASÍLIA"]}"#,
+    );
+
+    let client = BrasilApiClient::builder()
+        .cache(Cache::new(10).ttl(Duration::from_secs(60)))
+        .build_with_transport(transport.clone());
+
+    client.get_ddd("61").await.unwrap();
+    let ddd = client.get_ddd("61").await.unwrap();
+
+    assert_eq!(ddd.state, "DF");
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn fetch_transport_reaches_brasilapi() {
+    let cep = BrasilApiClient::new().get_cep("01001000").await.unwrap();
+
+    assert_eq!(cep.state, "SP");
+}